@@ -0,0 +1,35 @@
+//! Benchmarks of error creation.
+
+use ::core::{
+	error::Error,
+	fmt::{Display, Formatter, Result as FmtResult},
+	hint::black_box,
+};
+use ::criterion::{Criterion, criterion_group, criterion_main};
+use ::neuer_error::NeuErr;
+
+/// Source error without allocations of its own.
+#[derive(Debug)]
+struct SourceError;
+
+impl Display for SourceError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str("SourceError occurred")
+	}
+}
+
+impl Error for SourceError {}
+
+/// Benchmark creating errors from source errors, with and without context.
+fn create(c: &mut Criterion) {
+	c.bench_function("from source", |b| b.iter(|| NeuErr::from_source(black_box(SourceError))));
+	c.bench_function("from source with context", |b| {
+		b.iter(|| NeuErr::from_source(black_box(SourceError)).context("context"));
+	});
+	c.bench_function("new with context", |b| {
+		b.iter(|| NeuErr::new(black_box("message")).context("context"));
+	});
+}
+
+criterion_group!(benches, create);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+//! Benchmarks of error formatting.
+
+use ::core::fmt::Write;
+use ::criterion::{Criterion, criterion_group, criterion_main};
+use ::neuer_error::NeuErr;
+
+/// Benchmark formatting simple errors, with and without the single message fast path.
+fn format(c: &mut Criterion) {
+	let mut buffer = String::new();
+	let simple = NeuErr::new("Something went wrong");
+	c.bench_function("single context", |b| {
+		b.iter(|| {
+			buffer.clear();
+			_ = write!(buffer, "{simple}");
+		});
+	});
+
+	let attached = NeuErr::new("Something went wrong").attach(0_u8);
+	c.bench_function("single context with attachment", |b| {
+		b.iter(|| {
+			buffer.clear();
+			_ = write!(buffer, "{attached}");
+		});
+	});
+}
+
+criterion_group!(benches, format);
+criterion_main!(benches);
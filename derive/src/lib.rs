@@ -0,0 +1,67 @@
+//! Derive macros for [`neuer-error`](https://docs.rs/neuer-error), re-exported there with feature
+//! `derive`. Use them via `neuer_error::Attachment` instead of depending on this crate directly.
+
+use ::proc_macro::TokenStream;
+use ::quote::quote;
+use ::syn::{DeriveInput, LitStr, parse_macro_input};
+
+/// Implement `neuer_error::Attachment` for the type, registering its name for serialization and
+/// logging. The name defaults to the type's identifier and can be set via
+/// `#[attachment(name = "...")]`.
+///
+/// Opt into serializing the attachment by value via `#[attachment(serialize)]` (requires feature
+/// `serde` of `neuer-error` and a `Serialize` implementation) and cloning it when cloning the
+/// error via `#[attachment(clone)]` (requires a `Clone` implementation).
+#[proc_macro_derive(Attachment, attributes(attachment))]
+pub fn derive_attachment(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	derive_attachment_impl(input).unwrap_or_else(::syn::Error::into_compile_error).into()
+}
+
+/// Generate the `Attachment` implementation.
+fn derive_attachment_impl(input: DeriveInput) -> ::syn::Result<::proc_macro2::TokenStream> {
+	let mut name = LitStr::new(&input.ident.to_string(), input.ident.span());
+	let mut serialize = false;
+	let mut clone = false;
+	for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("attachment")) {
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("name") {
+				name = meta.value()?.parse()?;
+			} else if meta.path.is_ident("serialize") {
+				serialize = true;
+			} else if meta.path.is_ident("clone") {
+				clone = true;
+			} else {
+				return Err(meta.error(
+					"unsupported attachment attribute, expected `name`, `serialize` or `clone`",
+				));
+			}
+			Ok(())
+		})?;
+	}
+
+	let serialize = serialize.then(|| {
+		quote! {
+			#[doc(hidden)]
+			const SERIALIZE: ::core::option::Option<
+				fn(&Self) -> &dyn ::neuer_error::__ErasedSerialize,
+			> = ::core::option::Option::Some(|value| value);
+		}
+	});
+	let clone = clone.then(|| {
+		quote! {
+			#[doc(hidden)]
+			const CLONE: ::core::option::Option<fn(&Self) -> Self> =
+				::core::option::Option::Some(<Self as ::core::clone::Clone>::clone);
+		}
+	});
+	let ident = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	Ok(quote! {
+		impl #impl_generics ::neuer_error::Attachment for #ident #ty_generics #where_clause {
+			const NAME: &'static str = #name;
+			#serialize
+			#clone
+		}
+	})
+}
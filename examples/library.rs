@@ -14,18 +14,9 @@ use self::library::NeuErrAttachments;
 /// machine information is necessary to handle errors programmatically.
 ///
 /// When providing attachments, library authors should make use of the `provided_attachments!`
-/// macro!
+/// macro, or the `error_kinds!` macro to declare an error kind enum along with it!
 mod library {
-	use ::neuer_error::{NeuErr, Result, provided_attachments, traits::*};
-
-	/// Kinds of errors that are interesting to match on for library users.
-	/// If it is only interesting to humans, it can be iin the context instead.
-	#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-	#[non_exhaustive]
-	pub enum ErrorKind {
-		NotFound,
-		InvalidInput,
-	}
+	use ::neuer_error::{Result, error_kinds, traits::*};
 
 	/// Should the error be retried?
 	#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
@@ -35,18 +26,22 @@ mod library {
 	}
 
 	// Provide discoverable, typed information for library users.
-	provided_attachments!(
-		kind(single: ErrorKind) -> Option<ErrorKind> { |kind| kind.copied() };
+	error_kinds! {
+		/// Kinds of errors that are interesting to match on for library users.
+		/// If it is only interesting to humans, it can be iin the context instead.
+		pub enum ErrorKind {
+			NotFound,
+			InvalidInput,
+		}
+
 		retryable(single: Retryable) -> bool {
 			|retryable| matches!(retryable, Some(Retryable::Yes))
 		};
-	);
+	}
 
 	/// Implement your errors while attaching machine-targeted information.
 	fn do_something_internal() -> Result<()> {
-		Err(NeuErr::new("Error occurred internally")
-			.attach(ErrorKind::InvalidInput)
-			.attach(Retryable::No))
+		Err(ErrorKind::InvalidInput.error("Error occurred internally").attach(Retryable::No))
 	}
 
 	/// Alose provide human-targeted context when propagating errors.
@@ -6,8 +6,7 @@
 	reason = "Example"
 )]
 
-use ::neuer_error::{NeuErr, Result, traits::*};
-use ::std::process::ExitCode;
+use ::neuer_error::{NeuErr, Result, Severity, traits::*};
 
 fn ensure_project_validity() -> Result<()> {
 	Ok(())
@@ -24,10 +23,10 @@ fn compile_my_code() -> Result<()> {
 }
 
 fn lint() -> Result<()> {
-	Err(NeuErr::new("Warning: something is deprecated").attach_override(ExitCode::SUCCESS))
+	Err(NeuErr::new("Warning: something is deprecated").attach(Severity::Warning))
 }
 
-// Returning the error will automatically use the attached ExitCode or assume failure.
+// Returning the error will automatically use the attached ExitCode or Severity, or assume failure.
 fn main() -> Result<()> {
 	ensure_project_validity().context("Project is invalid")?;
 	compile_my_code().context("Failed compiling code")?;
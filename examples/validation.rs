@@ -6,7 +6,7 @@
 	reason = "Example"
 )]
 
-use ::neuer_error::{NeuErr, Result, traits::*};
+use ::neuer_error::{NeuErr, Result, format_err, partition_results, traits::*};
 
 struct UserData {
 	id: u64,
@@ -35,7 +35,7 @@ struct User {
 }
 
 impl User {
-	fn new(data: UserData) -> Result<Self, Vec<NeuErr>> {
+	fn new(data: UserData) -> Result<Self> {
 		let mut errors = Vec::new();
 		let UserData { id, name, balance } = data;
 
@@ -47,11 +47,15 @@ impl User {
 		}
 
 		if id == 3 {
-			errors.push(NeuErr::new(format!("User {id} ({name}) already exists")));
+			errors.push(format_err!("User {id} ({name}) already exists"));
 		}
 
 		let user = User { id, name, balance };
-		if errors.is_empty() { Ok(user) } else { Err(errors) }
+		if errors.is_empty() {
+			Ok(user)
+		} else {
+			Err(NeuErr::from_errors("Invalid user data", errors))
+		}
 	}
 }
 
@@ -60,11 +64,20 @@ fn main() {
 		Ok(_user) => {
 			eprintln!("User valid");
 		}
-		Err(errors) => {
-			for error in errors {
-				eprintln!("Error: {error}");
-				eprintln!();
-			}
+		Err(error) => {
+			eprintln!("Error: {error}");
 		}
 	}
+
+	// Validate a batch of users, keeping the valid ones and reporting the invalid ones.
+	let batch = [
+		UserData { id: 1, name: "Alice".to_owned(), balance: 100 },
+		UserData { id: 0, name: "Bob".to_owned(), balance: 0 },
+		UserData { id: 2, name: "Carol".to_owned(), balance: 50 },
+	];
+	let (users, errors) = partition_results(batch.into_iter().map(User::new));
+	eprintln!("{} users valid", users.len());
+	for error in errors {
+		eprintln!("Error: {error:#}");
+	}
 }
@@ -8,7 +8,8 @@
 
 use ::neuer_error::{NeuErr, Result, traits::*};
 
-/// Wrapper to convert errors to HTTP responses automatically.
+/// Wrapper to convert errors to HTTP responses automatically. With `axum`, the `axum` feature
+/// provides `NeuErrResponse` for this.
 #[derive(Debug)]
 struct ToResponse(NeuErr);
 
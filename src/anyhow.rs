@@ -0,0 +1,35 @@
+//! Interoperability with `anyhow` errors.
+
+use ::alloc::boxed::Box;
+use ::core::error::Error;
+
+use crate::NeuErr;
+
+impl NeuErr {
+	/// Convert an `anyhow` error, recording the location of the conversion.
+	///
+	/// The `anyhow` error is stored as source error, so its contexts and sources are rendered as
+	/// source chain. A `From` conversion would conflict with the conversion from all source errors,
+	/// so use this via `.map_err(NeuErr::from_anyhow)?`.
+	///
+	/// The conversion is lossy: `anyhow`'s backtrace is dropped and the source error can no longer
+	/// be downcast to the types wrapped by `anyhow`.
+	#[track_caller]
+	#[must_use]
+	#[inline]
+	pub fn from_anyhow(error: ::anyhow::Error) -> Self {
+		let boxed: Box<dyn Error + Send + Sync> = error.into();
+		Self::from_boxed(boxed)
+	}
+}
+
+#[cfg(all(feature = "send", feature = "sync"))]
+impl From<NeuErr> for ::anyhow::Error {
+	/// Convert the error into an `anyhow` error, e.g. to return it from functions using `anyhow`
+	/// during migration. The error is rendered in its usual format, but `anyhow` does not see its
+	/// human contexts as contexts and does not capture a backtrace.
+	#[inline]
+	fn from(error: NeuErr) -> Self {
+		Self::new(error.into_error())
+	}
+}
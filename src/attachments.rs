@@ -0,0 +1,513 @@
+//! Attachment types provided by the crate.
+
+use ::alloc::{
+	borrow::Cow,
+	string::{String, ToString},
+	vec::Vec,
+};
+#[cfg(any(feature = "serde_json", feature = "timestamp", feature = "thread-info"))]
+use ::core::fmt::{Formatter, Result as FmtResult};
+use ::core::{error::Error, fmt::Display};
+
+#[cfg(all(feature = "serde_json", feature = "serde"))]
+use crate::error::AttachmentHooks;
+use crate::{NeuErr, features::AnyDebugSendSync};
+
+/// Marker for attachment types containing secrets, like tokens or request bodies. Attachments
+/// added via [`NeuErr::attach_sensitive`] are removed via [`NeuErr::redact_sensitive`], e.g.
+/// before logging the error.
+///
+/// Attachments added via other methods, like [`NeuErr::attach`], are not marked. Remove them via
+/// [`NeuErr::redact`] instead.
+pub trait Sensitive: AnyDebugSendSync + 'static {}
+
+/// Attachment type with a registered name, which is used instead of the Rust type name when
+/// serializing or logging the error. Usually implemented via `#[derive(Attachment)]` with feature
+/// `derive`, optionally renamed via `#[attachment(name = "...")]`.
+///
+/// By default, attachments added via [`NeuErr::attach_named`] are serialized via their `Debug`
+/// output and not cloned via [`NeuErr::try_clone`]. Opt into serializing them by value (requires
+/// feature `serde` and a `Serialize` implementation) and cloning them (requires a `Clone`
+/// implementation) via `#[attachment(serialize, clone)]`.
+///
+/// The name is only registered by [`NeuErr::attach_named`] and [`Attachment::attach_to`].
+/// Attachments added via other methods, like [`NeuErr::attach`], use the Rust type name.
+pub trait Attachment: AnyDebugSendSync + Sized + 'static {
+	/// Registered name of the attachment type.
+	const NAME: &'static str;
+
+	/// Function to serialize the attachment by value, generated via `#[derive(Attachment)]` with
+	/// `#[attachment(serialize)]`. Serialized via its `Debug` output if `None`.
+	#[cfg(feature = "serde")]
+	#[doc(hidden)]
+	const SERIALIZE: Option<fn(&Self) -> &dyn ::erased_serde::Serialize> = None;
+
+	/// Function to clone the attachment, generated via `#[derive(Attachment)]` with
+	/// `#[attachment(clone)]`. Not cloned if `None`.
+	#[doc(hidden)]
+	const CLONE: Option<fn(&Self) -> Self> = None;
+
+	/// Add this attachment to the error via [`NeuErr::attach_named`].
+	#[must_use]
+	#[inline]
+	fn attach_to(self, error: NeuErr) -> NeuErr {
+		error.attach_named(self)
+	}
+}
+
+/// Structured diagnostic key-value fields, usually created via the [`fields!`](crate::fields)
+/// macro and attached via [`NeuErr::with_fields`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Fields(Vec<(&'static str, String)>);
+
+impl Fields {
+	/// Create an empty set of fields.
+	#[must_use]
+	#[inline]
+	pub const fn new() -> Self {
+		Self(Vec::new())
+	}
+
+	/// Add a field, capturing the value's `Display` output.
+	#[must_use]
+	#[inline]
+	pub fn with<V>(mut self, key: &'static str, value: V) -> Self
+	where
+		V: Display,
+	{
+		self.0.push((key, value.to_string()));
+		self
+	}
+
+	/// Get the value of the field with the given key.
+	#[must_use]
+	#[inline]
+	pub fn get(&self, key: &str) -> Option<&str> {
+		self.iter().find_map(|(k, v)| (k == key).then_some(v))
+	}
+
+	/// Get an iterator over the key-value pairs in insertion order.
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+		self.0.iter().map(|(key, value)| (*key, value.as_str()))
+	}
+
+	/// Whether there are no fields.
+	#[must_use]
+	#[inline]
+	pub const fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+}
+
+impl NeuErr {
+	/// Attach structured diagnostic key-value fields to the error. Use the
+	/// [`fields!`](crate::fields) macro to create them.
+	#[must_use]
+	#[inline]
+	pub fn with_fields(self, fields: Fields) -> Self {
+		self.attach_cloneable(fields)
+	}
+
+	/// Get an iterator over all structured fields of the error, newest attached first.
+	#[inline]
+	pub fn fields(&self) -> impl Iterator<Item = (&'static str, &str)> {
+		self.attachments::<Fields>().flat_map(Fields::iter)
+	}
+}
+
+/// Set of attachments of different types, attached at once via [`NeuErr::attach_many`].
+/// Implemented for tuples of up to 8 attachments.
+pub trait AttachmentSet {
+	/// Attach all attachments of the set to the error, in order.
+	fn attach_to(self, error: NeuErr) -> NeuErr;
+}
+
+/// Implement [`AttachmentSet`] for a tuple of the given type parameters.
+macro_rules! impl_attachment_set {
+	($($ty:ident),+) => {
+		impl<$($ty),+> AttachmentSet for ($($ty,)+)
+		where
+			$($ty: AnyDebugSendSync + 'static,)+
+		{
+			#[inline]
+			#[expect(non_snake_case, reason = "Reusing the type parameter names")]
+			fn attach_to(self, error: NeuErr) -> NeuErr {
+				let ($($ty,)+) = self;
+				error$(.attach($ty))+
+			}
+		}
+	};
+}
+
+impl_attachment_set!(A);
+impl_attachment_set!(A, B);
+impl_attachment_set!(A, B, C);
+impl_attachment_set!(A, B, C, D);
+impl_attachment_set!(A, B, C, D, E);
+impl_attachment_set!(A, B, C, D, E, F);
+impl_attachment_set!(A, B, C, D, E, F, G);
+impl_attachment_set!(A, B, C, D, E, F, G, H);
+
+impl NeuErr {
+	/// Add multiple machine contexts of different types to the error at once, given as tuple, e.g.
+	/// `error.attach_many((Retryable::No, StatusCode(404)))`.
+	#[must_use]
+	#[inline]
+	pub fn attach_many<S>(self, attachments: S) -> Self
+	where
+		S: AttachmentSet,
+	{
+		attachments.attach_to(self)
+	}
+}
+
+/// Human-facing note attachment, rendered in a separate section at the end of the error as
+/// `= note: ...`, not as part of the context chain. Attach it via [`NeuErr::note`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Note(pub Cow<'static, str>);
+
+/// Human-facing help attachment with a suggestion how to fix the error, rendered in a separate
+/// section at the end of the error as `= help: ...`. Attach it via [`NeuErr::help`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Help(pub Cow<'static, str>);
+
+impl NeuErr {
+	/// Attach a human-facing [`Note`], rendered at the end of the error.
+	#[must_use]
+	#[inline]
+	pub fn note<N>(self, note: N) -> Self
+	where
+		N: Into<Cow<'static, str>>,
+	{
+		self.attach_cloneable(Note(note.into()))
+	}
+
+	/// Attach a human-facing [`Help`] with a suggestion how to fix the error, rendered at the end
+	/// of the error.
+	#[must_use]
+	#[inline]
+	pub fn help<H>(self, help: H) -> Self
+	where
+		H: Into<Cow<'static, str>>,
+	{
+		self.attach_cloneable(Help(help.into()))
+	}
+}
+
+/// Attachment value keyed by a runtime key, attached via [`NeuErr::attach_for`]. Allows storing
+/// multiple values of the same type, distinguished by their key, e.g. a state machine phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Keyed<K, V> {
+	/// Key to look up the value by.
+	pub key: K,
+	/// Attached value.
+	pub value: V,
+}
+
+impl NeuErr {
+	/// Attach machine context keyed by a runtime key, e.g. an enum discriminant. Retrieve it via
+	/// [`attachment_for`](Self::attachment_for).
+	///
+	/// Unlike type-keyed attachments, this can store multiple values of the same type. The key is
+	/// stored alongside the value as [`Keyed`] attachment, so it adds the key's size to the
+	/// attachment and lookups compare the keys of all attachments of this key and value type.
+	#[must_use]
+	#[inline]
+	pub fn attach_for<K, V>(self, key: K, value: V) -> Self
+	where
+		K: AnyDebugSendSync + PartialEq + 'static,
+		V: AnyDebugSendSync + 'static,
+	{
+		self.attach(Keyed { key, value })
+	}
+
+	/// Get the newest machine context attachment of the given type attached for the given key via
+	/// [`attach_for`](Self::attach_for).
+	#[must_use]
+	#[inline]
+	pub fn attachment_for<K, V>(&self, key: &K) -> Option<&V>
+	where
+		K: AnyDebugSendSync + PartialEq + 'static,
+		V: AnyDebugSendSync + 'static,
+	{
+		self.attachments::<Keyed<K, V>>().find(|keyed| keyed.key == *key).map(|keyed| &keyed.value)
+	}
+}
+
+/// Stable machine-readable error code attachment, like `E1234` or `auth::token_expired`. The newest
+/// code is rendered in brackets before the top message, e.g. `[E1234] Failed`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ErrorCode(pub Cow<'static, str>);
+
+impl NeuErr {
+	/// Get the newest attached [`ErrorCode`], if any.
+	#[must_use]
+	#[inline]
+	pub fn code(&self) -> Option<&str> {
+		self.attachment::<ErrorCode>().map(|ErrorCode(code)| code.as_ref())
+	}
+
+	/// Compute an [`ErrorCode`] from the source error and attach it, if there is a source error.
+	///
+	/// This allows centralizing the mapping from source error types (via downcasting) to stable
+	/// codes in one function.
+	#[must_use]
+	#[inline]
+	pub fn code_from_source<F>(self, code_fn: F) -> Self
+	where
+		F: FnOnce(&(dyn Error + 'static)) -> Cow<'static, str>,
+	{
+		let code = self.0.chain().next().map(code_fn);
+		match code {
+			Some(code) => self.attach(ErrorCode(code)),
+			None => self,
+		}
+	}
+}
+
+/// Severity of an error, as attachment, e.g. to distinguish warnings from real failures in CLIs.
+/// Errors without this attachment are considered [`Severity::Error`].
+///
+/// With `std`, returning the error from `main` derives the process' exit code from the severity,
+/// unless an `ExitCode` is attached explicitly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Severity {
+	/// Something is not right, but the operation could proceed. Exit code `0`.
+	Warning,
+	/// The operation failed. Exit code `1`.
+	#[default]
+	Error,
+	/// The operation failed irrecoverably. Exit code `2`.
+	Fatal,
+}
+
+impl Severity {
+	/// Return code of an application terminating with an error of this severity.
+	#[must_use]
+	#[inline]
+	pub const fn return_code(self) -> u8 {
+		match self {
+			Self::Warning => 0,
+			Self::Error => 1,
+			Self::Fatal => 2,
+		}
+	}
+
+	/// Exit code of a process terminating with an error of this severity.
+	#[cfg(feature = "std")]
+	#[must_use]
+	#[inline]
+	pub fn exit_code(self) -> ::std::process::ExitCode {
+		::std::process::ExitCode::from(self.return_code())
+	}
+}
+
+/// Return code of an application terminating with an error, as attachment. It is the `no_std`
+/// equivalent of attaching an `ExitCode`, used via [`IntoReturnCode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReturnCode(pub u8);
+
+/// Conversion into a return code, similar to `std`'s `Termination` trait, but also available in
+/// `no_std`, e.g. to hand a code to the bootloader.
+///
+/// For errors, it is the attached [`ReturnCode`], or the return code of the attached
+/// [`Severity`], or `1`. Successful results have return code `0`.
+///
+/// ```
+/// # use neuer_error::{traits::*, NeuErr, Result, ReturnCode};
+/// fn run() -> Result<()> {
+/// 	Err(NeuErr::new("Sensor not responding").attach(ReturnCode(3)))
+/// }
+///
+/// // In the main loop:
+/// let code = run().context("Measurement failed").into_return_code();
+/// assert_eq!(code, 3);
+/// // Hand the code to the bootloader or reset reason register, e.g. `bootloader::exit(code)`.
+/// ```
+pub trait IntoReturnCode {
+	/// Get the return code.
+	fn into_return_code(self) -> u8;
+}
+
+impl IntoReturnCode for NeuErr {
+	#[inline]
+	fn into_return_code(self) -> u8 {
+		self.attachment::<ReturnCode>()
+			.map_or_else(|| self.severity().return_code(), |ReturnCode(code)| *code)
+	}
+}
+
+impl<T, E> IntoReturnCode for Result<T, E>
+where
+	E: IntoReturnCode,
+{
+	#[inline]
+	fn into_return_code(self) -> u8 {
+		match self {
+			Ok(_) => 0,
+			Err(error) => error.into_return_code(),
+		}
+	}
+}
+
+impl NeuErr {
+	/// Get the newest attached [`Severity`], or [`Severity::Error`] if there is none.
+	#[must_use]
+	#[inline]
+	pub fn severity(&self) -> Severity {
+		self.attachment::<Severity>().copied().unwrap_or_default()
+	}
+}
+
+/// Marker attached to errors converted from panics via [`NeuErr::catch_unwind`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Panicked;
+
+/// Creation time of an error, attached automatically at error creation with the `timestamp`
+/// feature. Retrieve it via [`NeuErr::created_at`].
+///
+/// It is displayed in RFC 3339 format in UTC with milliseconds, e.g.
+/// `2026-10-15T12:34:56.789Z`.
+#[cfg(feature = "timestamp")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CreatedAt(pub ::std::time::SystemTime);
+
+#[cfg(feature = "timestamp")]
+impl Display for CreatedAt {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		let Ok(since_epoch) = self.0.duration_since(::std::time::UNIX_EPOCH) else {
+			return write!(f, "{:?}", self.0);
+		};
+		let seconds = since_epoch.as_secs();
+		let (days, time) = (seconds / 86400, seconds % 86400);
+
+		// Convert the days since epoch to the civil date, see
+		// <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+		let days = days + 719_468;
+		let era = days / 146_097;
+		let day_of_era = days % 146_097;
+		let year_of_era =
+			(day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+		let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+		let month_index = (5 * day_of_year + 2) / 153;
+		let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+		let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+		let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+		write!(
+			f,
+			"{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+			time / 3600,
+			time % 3600 / 60,
+			time % 60,
+			since_epoch.subsec_millis()
+		)
+	}
+}
+
+#[cfg(feature = "timestamp")]
+impl NeuErr {
+	/// Get the creation time of the error.
+	#[must_use]
+	#[inline]
+	pub fn created_at(&self) -> Option<::std::time::SystemTime> {
+		self.attachment::<CreatedAt>().map(|CreatedAt(time)| *time)
+	}
+}
+
+/// Thread that created an error, attached automatically at error creation with the `thread-info`
+/// feature. Retrieve it via [`NeuErr::thread`].
+///
+/// It is displayed as the thread name, or the thread ID for unnamed threads.
+#[cfg(feature = "thread-info")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ThreadInfo {
+	/// Name of the thread, if it has one.
+	pub name: Option<String>,
+	/// Unique ID of the thread within the process.
+	pub id: ::std::thread::ThreadId,
+}
+
+#[cfg(feature = "thread-info")]
+impl ThreadInfo {
+	/// Get the info of the current thread.
+	#[must_use]
+	pub fn current() -> Self {
+		let thread = ::std::thread::current();
+		Self { name: thread.name().map(String::from), id: thread.id() }
+	}
+}
+
+#[cfg(feature = "thread-info")]
+impl Display for ThreadInfo {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match &self.name {
+			Some(name) => write!(f, "{name}"),
+			None => write!(f, "{:?}", self.id),
+		}
+	}
+}
+
+#[cfg(feature = "thread-info")]
+impl NeuErr {
+	/// Get the thread that created the error.
+	#[must_use]
+	#[inline]
+	pub fn thread(&self) -> Option<&ThreadInfo> {
+		self.attachment::<ThreadInfo>()
+	}
+}
+
+/// Attachment of a JSON value, e.g. the raw error payload of a JSON API. Attach it via
+/// [`NeuErr::attach_json`] to render it as part of the error: indented in the multi-line format and
+/// compact in the single-line format.
+#[cfg(feature = "serde_json")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonContext(pub ::serde_json::Value);
+
+#[cfg(feature = "serde_json")]
+impl Display for JsonContext {
+	/// Formats the JSON compactly, or indented with the alternate flag (`"{:#}"`).
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		Display::fmt(&self.0, f)
+	}
+}
+
+#[cfg(all(feature = "serde_json", feature = "serde"))]
+impl JsonContext {
+	/// Hooks to display the JSON value and serialize it by value.
+	const HOOKS: &'static AttachmentHooks = &AttachmentHooks {
+		display: AttachmentHooks::displayed::<Self>().display,
+		serialize: AttachmentHooks::serialized::<Self>().serialize,
+		clone: AttachmentHooks::cloneable::<Self>().clone,
+		sensitive: false,
+		name: None,
+	};
+}
+
+#[cfg(all(feature = "serde_json", feature = "serde"))]
+impl ::serde::Serialize for JsonContext {
+	#[inline]
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: ::serde::Serializer,
+	{
+		self.0.serialize(serializer)
+	}
+}
+
+#[cfg(feature = "serde_json")]
+impl NeuErr {
+	/// Attach a JSON value as [`JsonContext`], which is displayed when formatting the error.
+	#[must_use]
+	#[inline]
+	pub fn attach_json(self, value: ::serde_json::Value) -> Self {
+		#[cfg(not(feature = "serde"))]
+		return self.attach_display(JsonContext(value));
+		#[cfg(feature = "serde")]
+		return self.attach_with_hooks(JsonContext(value), JsonContext::HOOKS);
+	}
+}
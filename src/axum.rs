@@ -0,0 +1,32 @@
+//! Integration with `axum` for returning errors from request handlers.
+
+use ::alloc::format;
+use ::axum_core::response::{IntoResponse, Response};
+use ::http::StatusCode;
+
+use crate::NeuErr;
+
+/// Wrapper of [`NeuErr`] implementing `axum`'s `IntoResponse`, so errors can be returned from
+/// request handlers via `?`.
+///
+/// The response status is the newest attached `http::StatusCode`, or `500 Internal Server Error`
+/// if there is none. The body is the error in the multi-line format without colors. Mind that this
+/// exposes all error details to clients, so only use it if that is acceptable.
+#[derive(Debug)]
+pub struct NeuErrResponse(pub NeuErr);
+
+impl IntoResponse for NeuErrResponse {
+	fn into_response(self) -> Response {
+		let status =
+			self.0.attachment::<StatusCode>().copied().unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+		let body = format!("{}", self.0.as_report().without_colors());
+		(status, body).into_response()
+	}
+}
+
+impl From<NeuErr> for NeuErrResponse {
+	#[inline]
+	fn from(error: NeuErr) -> Self {
+		Self(error)
+	}
+}
@@ -0,0 +1,76 @@
+//! Integration with `defmt` for logging errors on embedded targets.
+
+use ::core::error::Error;
+#[cfg(test)]
+use ::core::fmt::{Display, Formatter as FmtFormatter, Result as FmtResult};
+use ::defmt::{Display2Format, Format, Formatter};
+
+use crate::{NeuErr, error::HumanInfo};
+
+/// Piece of the error emitted via `defmt`. Separating the pieces from emitting them allows
+/// testing the output without a `defmt` logger.
+#[derive(Clone, Copy)]
+pub(crate) enum Piece<'a> {
+	/// Separator between contexts and source errors.
+	Separator,
+	/// Human context message with its location.
+	Context(&'a HumanInfo),
+	/// Source error, formatted via its `Display` implementation.
+	Source(&'a (dyn Error + 'static)),
+}
+
+/// Pieces of the single-line format of the error: The human context messages with their
+/// locations, newest first, followed by the source errors, separated by [`Piece::Separator`].
+pub(crate) fn pieces(error: &NeuErr) -> impl Iterator<Item = Piece<'_>> {
+	let contexts = error.0.contexts().map(Piece::Context);
+	let sources = error.0.chain().map(Piece::Source);
+	contexts.chain(sources).enumerate().flat_map(|(i, piece)| {
+		let separator = (i > 0).then_some(Piece::Separator);
+		separator.into_iter().chain(::core::iter::once(piece))
+	})
+}
+
+impl Format for Piece<'_> {
+	fn format(&self, f: Formatter<'_>) {
+		match self {
+			Self::Separator => ::defmt::write!(f, "; "),
+			Self::Context(context) => ::defmt::write!(
+				f,
+				"{=str} (at {=str}:{=u32})",
+				context.message.as_ref(),
+				context.location.file(),
+				context.location.line()
+			),
+			Self::Source(source) => ::defmt::write!(f, "caused by: {}", Display2Format(source)),
+		}
+	}
+}
+
+/// Renders pieces like `defmt` does, to test the output.
+#[cfg(test)]
+impl Display for Piece<'_> {
+	fn fmt(&self, f: &mut FmtFormatter<'_>) -> FmtResult {
+		match self {
+			Self::Separator => write!(f, "; "),
+			Self::Context(context) => write!(
+				f,
+				"{} (at {}:{})",
+				context.message,
+				context.location.file(),
+				context.location.line()
+			),
+			Self::Source(source) => write!(f, "caused by: {source}"),
+		}
+	}
+}
+
+impl Format for NeuErr {
+	/// Emits the single-line format of the error: The human context messages with their locations,
+	/// newest first, followed by the source errors. Messages and locations are written directly,
+	/// only source errors are formatted via their `Display` implementation.
+	fn format(&self, f: Formatter<'_>) {
+		for piece in pieces(self) {
+			piece.format(f);
+		}
+	}
+}
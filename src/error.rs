@@ -1,20 +1,29 @@
 //! Error type implementation.
 
-use ::alloc::{borrow::Cow, boxed::Box, vec, vec::Vec};
+use ::alloc::{
+	borrow::Cow,
+	boxed::Box,
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
 use ::core::{
-	any::Any,
+	any::{Any, TypeId},
 	error::Error,
 	fmt::{Debug, Display, Formatter, Result as FmtResult},
 	panic::Location,
 };
-#[cfg(feature = "colors")]
-use ::yansi::Paint;
+#[cfg(feature = "backtrace")]
+use ::std::backtrace::{Backtrace, BacktraceStatus};
 
-use crate::features::{AnyDebugSendSync, ErrorSendSync};
+use crate::{
+	FormatOptions, LocationStyle, Report,
+	features::{AnyDebugSendSync, CloneableAny, ErrorSendSync, SendSync},
+};
 
 /// Error information for humans.
 /// Error message with location information.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct HumanInfo {
 	/// Message text.
 	pub(crate) message: Cow<'static, str>,
@@ -24,10 +33,303 @@ pub(crate) struct HumanInfo {
 
 /// Error information for machines.
 /// Arbitrary, project specific types of information.
-#[derive(Debug)]
 pub(crate) struct MachineInfo {
 	/// Attachment.
 	pub(crate) attachment: Box<dyn AnyDebugSendSync>,
+	/// Type-erased functions to handle the attachment, shared for all attachments of its type.
+	pub(crate) hooks: &'static AttachmentHooks,
+}
+
+#[expect(clippy::missing_fields_in_debug, reason = "Function pointers are not informative")]
+impl Debug for MachineInfo {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.debug_struct("MachineInfo").field("attachment", &self.attachment).finish()
+	}
+}
+
+impl MachineInfo {
+	/// Create machine context info from an attachment that is not displayed.
+	#[inline]
+	pub(crate) fn new<C>(attachment: C) -> Self
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		Self::with_hooks(attachment, &AttachmentHooks::NONE)
+	}
+
+	/// Create machine context info from an attachment with the given hooks.
+	#[inline]
+	pub(crate) fn with_hooks<C>(attachment: C, hooks: &'static AttachmentHooks) -> Self
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		Self { attachment: Box::new(attachment), hooks }
+	}
+
+	/// Get the displayable attachment, if it should be displayed when formatting the error.
+	#[inline]
+	pub(crate) fn displayed(&self) -> Option<DisplayedAttachment<'_>> {
+		self.hooks
+			.display
+			.map(|display| DisplayedAttachment { attachment: self.attachment.as_ref(), display })
+	}
+
+	/// Get the attachment as serializable value, if it was attached as such.
+	#[cfg(feature = "serde")]
+	#[inline]
+	pub(crate) fn serializable(&self) -> Option<&dyn ::erased_serde::Serialize> {
+		self.hooks.serialize.and_then(|serialize| serialize(self.attachment.as_ref()))
+	}
+
+	/// Clone the attachment, if it was attached as cloneable.
+	#[inline]
+	fn try_clone(&self) -> Option<Self> {
+		let attachment = (self.hooks.clone?)(self.attachment.as_ref())?;
+		Some(Self { attachment, hooks: self.hooks })
+	}
+
+	/// Name of the attachment type, preferring the registered name over the Rust type name.
+	#[cfg(any(feature = "serde", feature = "log", feature = "sentry", feature = "opentelemetry"))]
+	#[inline]
+	pub(crate) fn type_name(&self) -> &'static str {
+		self.hooks.name.unwrap_or_else(|| self.attachment.as_ref().type_name())
+	}
+
+	/// Whether the attachment is attached automatically at error creation, e.g. the creation time
+	/// with the `timestamp` feature.
+	#[cfg(any(test, feature = "test-utils"))]
+	pub(crate) fn is_automatic(&self) -> bool {
+		let automatic = [
+			#[cfg(feature = "timestamp")]
+			TypeId::of::<crate::CreatedAt>(),
+			#[cfg(feature = "thread-info")]
+			TypeId::of::<crate::ThreadInfo>(),
+			#[cfg(feature = "span-fields")]
+			TypeId::of::<crate::SpanFields>(),
+		];
+		#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+		let attachment = self.attachment.as_ref() as &(dyn Any + 'static);
+		automatic.contains(&attachment.type_id())
+	}
+}
+
+/// Type-erased functions to handle attachments of a specific type.
+#[derive(Debug)]
+pub(crate) struct AttachmentHooks {
+	/// Function to display the attachment, if it should be displayed when formatting the error.
+	pub(crate) display: Option<DisplayFn>,
+	/// Function to access the attachment as serializable value.
+	#[cfg(feature = "serde")]
+	pub(crate) serialize: Option<SerializeFn>,
+	/// Function to clone the attachment.
+	pub(crate) clone: Option<CloneFn>,
+	/// Whether the attachment is removed via [`NeuErr::redact_sensitive`].
+	pub(crate) sensitive: bool,
+	/// Registered name of the attachment type, used instead of the Rust type name.
+	#[cfg_attr(
+		not(any(
+			feature = "serde",
+			feature = "log",
+			feature = "sentry",
+			feature = "opentelemetry"
+		)),
+		expect(dead_code, reason = "Only read when serializing or logging errors")
+	)]
+	pub(crate) name: Option<&'static str>,
+}
+
+impl AttachmentHooks {
+	/// Hooks for attachments that are neither displayed nor serialized.
+	pub(crate) const NONE: Self = Self {
+		display: None,
+		#[cfg(feature = "serde")]
+		serialize: None,
+		clone: None,
+		sensitive: false,
+		name: None,
+	};
+
+	/// Hooks for attachments that are displayed when formatting the error.
+	pub(crate) const fn displayed<C>() -> &'static Self
+	where
+		C: AnyDebugSendSync + Display + 'static,
+	{
+		const {
+			&Self {
+				display: Some(display_as::<C>),
+				#[cfg(feature = "serde")]
+				serialize: None,
+				clone: None,
+				sensitive: false,
+				name: None,
+			}
+		}
+	}
+
+	/// Hooks for attachments that are serialized by value when serializing the error.
+	#[cfg(feature = "serde")]
+	pub(crate) const fn serialized<C>() -> &'static Self
+	where
+		C: AnyDebugSendSync + ::serde::Serialize + 'static,
+	{
+		const {
+			&Self {
+				display: None,
+				serialize: Some(serialize_as::<C>),
+				clone: None,
+				sensitive: false,
+				name: None,
+			}
+		}
+	}
+
+	/// Hooks for attachments that are cloned when cloning the error.
+	pub(crate) const fn cloneable<C>() -> &'static Self
+	where
+		C: CloneableAny + 'static,
+	{
+		const {
+			&Self {
+				display: None,
+				#[cfg(feature = "serde")]
+				serialize: None,
+				clone: Some(clone_as::<C>),
+				sensitive: false,
+				name: None,
+			}
+		}
+	}
+
+	/// Hooks for sensitive attachments that are removed via [`NeuErr::redact_sensitive`].
+	pub(crate) const SENSITIVE: Self = Self {
+		display: None,
+		#[cfg(feature = "serde")]
+		serialize: None,
+		clone: None,
+		sensitive: true,
+		name: None,
+	};
+
+	/// Hooks for attachments with a registered name, see [`Attachment`](crate::Attachment). They
+	/// are serialized by value and cloned if the type opted into it.
+	pub(crate) const fn named<C>() -> &'static Self
+	where
+		C: crate::Attachment,
+	{
+		const {
+			&Self {
+				display: None,
+				#[cfg(feature = "serde")]
+				serialize: if C::SERIALIZE.is_some() { Some(serialize_named::<C>) } else { None },
+				clone: if C::CLONE.is_some() { Some(clone_named::<C>) } else { None },
+				sensitive: false,
+				name: Some(C::NAME),
+			}
+		}
+	}
+}
+
+/// Function to clone a type-erased attachment.
+pub(crate) type CloneFn = fn(&dyn AnyDebugSendSync) -> Option<Box<dyn AnyDebugSendSync>>;
+
+/// Clone a type-erased attachment of type `C`.
+fn clone_as<C>(attachment: &dyn AnyDebugSendSync) -> Option<Box<dyn AnyDebugSendSync>>
+where
+	C: CloneableAny + 'static,
+{
+	#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+	(attachment as &(dyn Any + 'static)).downcast_ref::<C>().map(CloneableAny::clone_box)
+}
+
+/// Clone a type-erased [`Attachment`](crate::Attachment) of type `C`, if it opted into cloning.
+fn clone_named<C>(attachment: &dyn AnyDebugSendSync) -> Option<Box<dyn AnyDebugSendSync>>
+where
+	C: crate::Attachment,
+{
+	#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+	let attachment = (attachment as &(dyn Any + 'static)).downcast_ref::<C>()?;
+	C::CLONE.map(|clone| -> Box<dyn AnyDebugSendSync> { Box::new(clone(attachment)) })
+}
+
+/// Function to clone a type-erased source error.
+type SourceCloneFn = fn(&(dyn ErrorSendSync + 'static)) -> Option<Box<dyn ErrorSendSync>>;
+
+/// Clone a type-erased source error of type `E`.
+fn clone_source_as<E>(source: &(dyn ErrorSendSync + 'static)) -> Option<Box<dyn ErrorSendSync>>
+where
+	E: ErrorSendSync + Clone + 'static,
+{
+	#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+	(source as &(dyn Error + 'static))
+		.downcast_ref::<E>()
+		.map(|source| -> Box<dyn ErrorSendSync> { Box::new(source.clone()) })
+}
+
+/// Function to access a type-erased attachment as serializable value.
+#[cfg(feature = "serde")]
+pub(crate) type SerializeFn = fn(&dyn AnyDebugSendSync) -> Option<&dyn ::erased_serde::Serialize>;
+
+/// Access a type-erased attachment of type `C` as serializable value.
+#[cfg(feature = "serde")]
+fn serialize_as<C>(attachment: &dyn AnyDebugSendSync) -> Option<&dyn ::erased_serde::Serialize>
+where
+	C: AnyDebugSendSync + ::serde::Serialize + 'static,
+{
+	#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+	(attachment as &(dyn Any + 'static))
+		.downcast_ref::<C>()
+		.map(|attachment| attachment as &dyn ::erased_serde::Serialize)
+}
+
+/// Access a type-erased [`Attachment`](crate::Attachment) of type `C` as serializable value, if it
+/// opted into serializing by value.
+#[cfg(feature = "serde")]
+fn serialize_named<C>(attachment: &dyn AnyDebugSendSync) -> Option<&dyn ::erased_serde::Serialize>
+where
+	C: crate::Attachment,
+{
+	#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+	let attachment = (attachment as &(dyn Any + 'static)).downcast_ref::<C>()?;
+	C::SERIALIZE.map(|serialize| serialize(attachment))
+}
+
+/// Function to display a type-erased attachment.
+pub(crate) type DisplayFn = fn(&dyn AnyDebugSendSync, &mut Formatter<'_>) -> FmtResult;
+
+/// Display a type-erased attachment of type `C`.
+fn display_as<C>(attachment: &dyn AnyDebugSendSync, f: &mut Formatter<'_>) -> FmtResult
+where
+	C: AnyDebugSendSync + Display + 'static,
+{
+	#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+	match (attachment as &(dyn Any + 'static)).downcast_ref::<C>() {
+		Some(attachment) => Display::fmt(attachment, f),
+		None => Ok(()),
+	}
+}
+
+/// Attachment that is displayed when formatting the error.
+#[derive(Clone, Copy)]
+pub(crate) struct DisplayedAttachment<'a> {
+	/// Type-erased attachment.
+	attachment: &'a dyn AnyDebugSendSync,
+	/// Function to display the attachment.
+	display: DisplayFn,
+}
+
+impl DisplayedAttachment<'_> {
+	/// Name of the attachment's concrete type.
+	#[inline]
+	pub(crate) fn type_name(&self) -> &'static str {
+		self.attachment.type_name()
+	}
+}
+
+impl Display for DisplayedAttachment<'_> {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		(self.display)(self.attachment, f)
+	}
 }
 
 /// Context information, either machine or human.
@@ -39,6 +341,38 @@ pub(crate) enum Info {
 	/// Contextual information for machines.
 	Machine(MachineInfo),
 }
+
+/// Storage of the context information of an error.
+#[cfg(not(feature = "smallvec"))]
+pub(crate) type Infos = Vec<Info>;
+/// Storage of the context information of an error, keeping the first two infos inline to avoid
+/// allocating for typical errors.
+#[cfg(feature = "smallvec")]
+pub(crate) type Infos = ::smallvec::SmallVec<[Info; 2]>;
+impl Info {
+	/// Clone the info, if it is cloneable.
+	#[inline]
+	fn try_clone(&self) -> Option<Self> {
+		match self {
+			Self::Human(info) => Some(Self::Human(info.clone())),
+			Self::Machine(info) => info.try_clone().map(Self::Machine),
+		}
+	}
+
+	/// Whether the info is a machine context attachment of the given type.
+	#[inline]
+	fn is_attachment<C>(&self) -> bool
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+		match self {
+			Self::Machine(ctx) => (ctx.attachment.as_ref() as &(dyn Any + 'static)).is::<C>(),
+			Self::Human(_) => false,
+		}
+	}
+}
+
 // Ensure niche-optimization is active.
 const _: () = {
 	assert!(size_of::<Info>() == size_of::<HumanInfo>());
@@ -58,18 +392,205 @@ const _: () = {
 /// When using the `Display` implementation, the normal implementation (`"{err}"`) will use
 /// multi-line formatting. You can use the alternate format (`{err:#}`) to get a compact single-line
 /// version. instead of multi-line formatted.
+///
+/// Use [`NeuErr::as_report`] to customize formatting options.
+///
+/// ## Usage in Tests
+///
+/// Tests returning `Result<(), NeuErr>` print the error via its normal `Debug` implementation on
+/// failure, so failing tests show the same readable multi-line format (colored, if enabled):
+///
+/// ```rust,no_run
+/// # use neuer_error::{NeuErr, Result};
+/// #[test]
+/// fn my_test() -> Result<()> {
+/// 	Err(NeuErr::new("Inner error").context("Test failed"))
+/// }
+/// ```
+///
+/// ```text
+/// Error: Test failed
+/// |- at src/lib.rs:3:33
+/// |
+/// Inner error
+/// |- at src/lib.rs:3:6
+/// ```
 #[derive(Default)]
-pub struct NeuErr(NeuErrImpl);
+pub struct NeuErr(pub(crate) Box<NeuErrImpl>);
 
 /// Inner implementation of [`NeuErr`] that implements [`Error`].
 #[derive(Default)]
 pub struct NeuErrImpl {
-	/// Contextual error information.
-	infos: Vec<Info>,
+	/// Contextual error information. Starts empty without allocating, so converting source errors
+	/// only allocates once the first context or attachment is added (or never for the first two
+	/// with the `smallvec` feature).
+	infos: Infos,
 	/// Source error.
 	source: Option<Box<dyn ErrorSendSync>>,
+	/// Length of the source error chain, computed once when the source is set.
+	source_depth: usize,
+	/// Location where the source error was converted into this error, if recorded.
+	source_location: Option<&'static Location<'static>>,
+	/// Function to clone the source error, if it is cloneable.
+	source_clone: Option<SourceCloneFn>,
+	/// Backtrace captured at error creation, if enabled via environment variables.
+	#[cfg(feature = "backtrace")]
+	backtrace: Option<Backtrace>,
+}
+
+/// Add the creation time to the infos of a newly created error.
+#[cfg(feature = "timestamp")]
+fn with_created_at(mut infos: Infos) -> Infos {
+	let created_at = crate::CreatedAt(::std::time::SystemTime::now());
+	infos.push(Info::Machine(MachineInfo::with_hooks(
+		created_at,
+		AttachmentHooks::cloneable::<crate::CreatedAt>(),
+	)));
+	infos
+}
+
+/// Add the current thread to the infos of a newly created error.
+#[cfg(feature = "thread-info")]
+fn with_thread_info(mut infos: Infos) -> Infos {
+	infos.push(Info::Machine(MachineInfo::with_hooks(
+		crate::ThreadInfo::current(),
+		AttachmentHooks::cloneable::<crate::ThreadInfo>(),
+	)));
+	infos
+}
+
+/// Emit a `tracing` event for a newly created error, to see where errors originate.
+#[cfg(feature = "trace-creation")]
+fn trace_creation(context: &HumanInfo, source: Option<&dyn ErrorSendSync>) {
+	::tracing::debug!(
+		target: "neuer_error",
+		file = context.location.file(),
+		line = context.location.line(),
+		source = source.map(::tracing::field::display),
+		"{}",
+		context.message
+	);
+}
+
+/// Capture a backtrace, if enabled via environment variables.
+#[cfg(feature = "backtrace")]
+fn capture_backtrace() -> Option<Backtrace> {
+	let backtrace = Backtrace::capture();
+	(backtrace.status() == BacktraceStatus::Captured).then_some(backtrace)
+}
+
+/// Conversion of errors, including [`NeuErr`], into type-erased source errors.
+pub trait IntoSourceError {
+	/// Convert into a type-erased source error.
+	fn into_source_error(self) -> Box<dyn ErrorSendSync>;
+}
+
+impl<E> IntoSourceError for E
+where
+	E: ErrorSendSync + 'static,
+{
+	#[inline]
+	fn into_source_error(self) -> Box<dyn ErrorSendSync> {
+		Box::new(self)
+	}
+}
+
+impl IntoSourceError for NeuErr {
+	#[inline]
+	fn into_source_error(self) -> Box<dyn ErrorSendSync> {
+		self.0
+	}
+}
+
+/// Boxed source error trait object, stored via [`NeuErr::from_boxed`].
+struct BoxedError<E: ?Sized>(Box<E>);
+
+impl<E: Debug + ?Sized> Debug for BoxedError<E> {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		Debug::fmt(&self.0, f)
+	}
+}
+
+impl<E: Display + ?Sized> Display for BoxedError<E> {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		Display::fmt(&self.0, f)
+	}
 }
 
+impl<E: Error + ?Sized> Error for BoxedError<E> {
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		self.0.source()
+	}
+}
+
+/// Multiple errors that occurred together, used as source error of an aggregated [`NeuErr`].
+///
+/// On its own, it is displayed as the number of errors, while the formatting of [`NeuErr`] renders
+/// each of the errors individually.
+#[derive(Debug)]
+pub struct MultipleErrors(Vec<Box<dyn ErrorSendSync>>);
+
+impl MultipleErrors {
+	/// Get an iterator over the errors.
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = &'_ (dyn ErrorSendSync + 'static)> {
+		self.0.iter().map(AsRef::as_ref)
+	}
+
+	/// Number of errors.
+	#[must_use]
+	#[inline]
+	pub const fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	/// Whether there are no errors.
+	#[must_use]
+	#[inline]
+	pub const fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+}
+
+impl Display for MultipleErrors {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		write!(f, "{} errors occurred", self.len())
+	}
+}
+
+impl Error for MultipleErrors {}
+
+/// Wrapper for source errors to capture the source location when converting into [`NeuErr`].
+///
+/// Wrapping the error records the location of the conversion, e.g. the `?` in
+/// `foo().map_err(Tracked)?`, which is rendered next to the source error. The blanket `From`
+/// conversion records the location as well, so this is only needed to be explicit about it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Tracked<E>(pub E);
+
+/// Ad-hoc error message converting into a new [`NeuErr`] with the message, capturing the location
+/// of the conversion.
+///
+/// A direct conversion from `&str`, `String` or `Cow<'static, str>` would conflict with the
+/// conversion from all source errors, so the message is wrapped instead:
+///
+/// ```rust
+/// # use neuer_error::{Msg, NeuErr, Result};
+/// fn first_word(input: &str) -> Result<&str> {
+/// 	let word = input.split_whitespace().next().ok_or(Msg("Input must not be empty"))?;
+/// 	Ok(word)
+/// }
+///
+/// assert!(first_word("").is_err());
+/// let error = NeuErr::from(Msg(format!("Invalid input: {}", 5)));
+/// assert_eq!(error.message(), Some("Invalid input: 5"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Msg<M>(pub M);
+
 impl Debug for NeuErr {
 	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
 		Debug::fmt(&self.0, f)
@@ -82,6 +603,10 @@ impl Display for NeuErr {
 	}
 }
 
+#[expect(
+	clippy::missing_fields_in_debug,
+	reason = "Derived data and locations are not informative"
+)]
 impl Debug for NeuErrImpl {
 	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
 		if f.alternate() {
@@ -97,66 +622,16 @@ impl Debug for NeuErrImpl {
 
 impl Display for NeuErrImpl {
 	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-		let mut human = self.contexts().peekable();
-		if human.peek().is_none() {
-			#[cfg(feature = "colors")]
-			let unknown = "Unknown error".red();
-			#[cfg(not(feature = "colors"))]
-			let unknown = "Unknown error";
-
-			write!(f, "{unknown}")?;
-		}
-		while let Some(context) = human.next() {
-			#[cfg(feature = "colors")]
-			let message = context.message.as_ref().red();
-			#[cfg(not(feature = "colors"))]
-			let message = context.message.as_ref();
-
-			#[cfg(feature = "colors")]
-			let location = context.location.rgb(0x90, 0x90, 0x90);
-			#[cfg(not(feature = "colors"))]
-			let location = context.location;
-
-			if f.alternate() {
-				write!(f, "{message} (at {location})")?;
-				if human.peek().is_some() {
-					write!(f, "; ")?;
-				}
-			} else {
-				writeln!(f, "{message}")?;
-				write!(f, "|- at {location}")?;
-				if human.peek().is_some() {
-					writeln!(f)?;
-					writeln!(f, "|")?;
-				}
-			}
-		}
-
-		#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
-		let mut source = self.source.as_deref().map(|e| e as &(dyn Error + 'static));
-		while let Some(err) = source {
-			#[cfg(feature = "colors")]
-			let error = err.red();
-			#[cfg(not(feature = "colors"))]
-			let error = err;
-
-			if f.alternate() {
-				write!(f, "; caused by: {error}")?;
-			} else {
-				writeln!(f)?;
-				writeln!(f, "|")?;
-				write!(f, "|- caused by: {error}")?;
-			}
-
-			source = err.source();
-		}
-
-		Ok(())
+		Display::fmt(&self.as_report(), f)
 	}
 }
 
 impl NeuErr {
 	/// Create new error.
+	///
+	/// There is no `From<&str>` or `From<String>` conversion for ad-hoc errors, because it would
+	/// conflict with the conversion from all source errors, which the `?` operator relies on. Wrap
+	/// the message in [`Msg`] to convert it instead, e.g. `value.ok_or(Msg("Missing value"))?`.
 	#[track_caller]
 	#[must_use]
 	#[inline]
@@ -164,9 +639,22 @@ impl NeuErr {
 	where
 		C: Into<Cow<'static, str>>,
 	{
-		let infos =
-			vec![Info::Human(HumanInfo { message: context.into(), location: Location::caller() })];
-		Self(NeuErrImpl { infos, ..Default::default() })
+		let context = HumanInfo { message: context.into(), location: Location::caller() };
+		#[cfg(feature = "trace-creation")]
+		trace_creation(&context, None);
+		let infos = ::core::iter::once(Info::Human(context)).collect::<Infos>();
+		#[cfg(feature = "span-fields")]
+		let infos = crate::tracing::with_span_fields(infos);
+		#[cfg(feature = "timestamp")]
+		let infos = with_created_at(infos);
+		#[cfg(feature = "thread-info")]
+		let infos = with_thread_info(infos);
+		Self(Box::new(NeuErrImpl {
+			infos,
+			#[cfg(feature = "backtrace")]
+			backtrace: capture_backtrace(),
+			..Default::default()
+		}))
 	}
 
 	/// Create new error from source error.
@@ -178,9 +666,31 @@ impl NeuErr {
 		C: Into<Cow<'static, str>>,
 		E: ErrorSendSync + 'static,
 	{
-		let infos =
-			vec![Info::Human(HumanInfo { message: context.into(), location: Location::caller() })];
-		Self(NeuErrImpl { infos, source: Some(Box::new(source)) })
+		let context = HumanInfo { message: context.into(), location: Location::caller() };
+		#[cfg(feature = "trace-creation")]
+		trace_creation(&context, Some(&source));
+		let infos = ::core::iter::once(Info::Human(context)).collect::<Infos>();
+		#[cfg(feature = "span-fields")]
+		let infos = crate::tracing::with_span_fields(infos);
+		Self(Box::new(NeuErrImpl::from_parts(infos, source)))
+	}
+
+	/// Create new error from multiple source errors, e.g. from concurrent operations that failed
+	/// or from collected validation errors. The errors can be other errors or [`NeuErr`]s.
+	///
+	/// The errors are stored as [`MultipleErrors`] source error and rendered individually as
+	/// numbered sub-errors when formatting the error.
+	#[track_caller]
+	#[must_use]
+	#[inline]
+	pub fn from_errors<C, I>(context: C, errors: I) -> Self
+	where
+		C: Into<Cow<'static, str>>,
+		I: IntoIterator,
+		I::Item: IntoSourceError,
+	{
+		let errors = errors.into_iter().map(IntoSourceError::into_source_error);
+		Self::new_with_source(context, MultipleErrors(errors.collect()))
 	}
 
 	/// Convert source error.
@@ -190,122 +700,922 @@ impl NeuErr {
 	where
 		E: ErrorSendSync + 'static,
 	{
-		Self(NeuErrImpl { source: Some(Box::new(source)), ..Default::default() })
+		Self(Box::new(NeuErrImpl::from_parts(Infos::new(), source)))
 	}
 
-	/// Add human context to the error.
+	/// Convert a boxed source error trait object, like `Box<dyn Error + Send + Sync>` returned by
+	/// many libraries, recording the location of the conversion.
+	///
+	/// Boxed trait objects do not implement `Error`, and a `From` conversion would conflict with
+	/// the conversion from all source errors, so use this via `.map_err(NeuErr::from_boxed)?`.
+	/// The boxed error is wrapped, so [`downcast_source_ref`](Self::downcast_source_ref) does not
+	/// see its concrete type, but its source chain is preserved.
 	#[track_caller]
 	#[must_use]
 	#[inline]
-	pub fn context<C>(self, context: C) -> Self
+	pub fn from_boxed<E>(source: Box<E>) -> Self
 	where
-		C: Into<Cow<'static, str>>,
+		E: Error + SendSync + ?Sized + 'static,
 	{
-		Self(self.0.context(context))
+		Self::from_source_at(BoxedError(source), Location::caller())
 	}
 
-	/// Add machine context to the error.
-	///
-	/// This will not override existing attachments. If you want to replace and override any
-	/// existing attachments of the same type, use `attach_override` instead.
+	/// Create new error from a cloneable source error, which is cloned in
+	/// [`NeuErr::try_clone`].
+	#[track_caller]
 	#[must_use]
 	#[inline]
-	pub fn attach<C>(self, context: C) -> Self
+	pub fn new_with_cloneable_source<C, E>(context: C, source: E) -> Self
 	where
-		C: AnyDebugSendSync + 'static,
+		C: Into<Cow<'static, str>>,
+		E: ErrorSendSync + Clone + 'static,
 	{
-		Self(self.0.attach(context))
+		let mut error = Self::new_with_source(context, source);
+		error.0.source_clone = Some(clone_source_as::<E>);
+		error
 	}
 
-	/// Set machine context in the error.
-	///
-	/// This will override existing attachments of the same type. If you want to add attachments of
-	/// the same type, use `attach` instead.
+	/// Convert cloneable source error, which is cloned in [`NeuErr::try_clone`].
 	#[must_use]
-	pub fn attach_override<C>(self, context: C) -> Self
+	#[inline]
+	pub fn from_cloneable_source<E>(source: E) -> Self
 	where
-		C: AnyDebugSendSync + 'static,
+		E: ErrorSendSync + Clone + 'static,
 	{
-		Self(self.0.attach_override(context))
+		let mut error = Self::from_source(source);
+		error.0.source_clone = Some(clone_source_as::<E>);
+		error
 	}
 
-	/// Get an iterator over the human context infos.
+	/// Convert source error, recording the location of the conversion.
 	#[inline]
-	#[cfg_attr(not(test), expect(unused, reason = "For consistency"))]
-	pub(crate) fn contexts(&self) -> impl Iterator<Item = &'_ HumanInfo> {
-		self.0.contexts()
+	fn from_source_at<E>(source: E, location: &'static Location<'static>) -> Self
+	where
+		E: ErrorSendSync + 'static,
+	{
+		let mut error = NeuErrImpl::from_parts(Infos::new(), source);
+		error.source_location = Some(location);
+		Self(Box::new(error))
 	}
 
-	/// Get an iterator over the machine context attachments of the given type.
+	/// Add human context to the error.
+	#[track_caller]
+	#[must_use]
 	#[inline]
-	pub fn attachments<C>(&self) -> impl Iterator<Item = &'_ C>
+	pub fn context<C>(mut self, context: C) -> Self
+	where
+		C: Into<Cow<'static, str>>,
+	{
+		let context = HumanInfo { message: context.into(), location: Location::caller() };
+		self.0.infos.push(Info::Human(context));
+		self
+	}
+
+	/// Add human context to the error as its oldest context, so that it is rendered below all
+	/// existing contexts, but still above the source error.
+	///
+	/// Contexts are rendered newest first, so usually the call order determines the rendered
+	/// order. This allows to construct the high-level message first and add lower-level details
+	/// afterwards:
+	///
+	/// ```
+	/// # use neuer_error::NeuErr;
+	/// let error = NeuErr::new("Failed loading config").context_inner("File is empty");
+	/// assert_eq!(error.messages().collect::<Vec<_>>(), ["Failed loading config", "File is empty"]);
+	/// ```
+	#[track_caller]
+	#[must_use]
+	#[inline]
+	pub fn context_inner<C>(mut self, context: C) -> Self
+	where
+		C: Into<Cow<'static, str>>,
+	{
+		let context = HumanInfo { message: context.into(), location: Location::caller() };
+		self.0.infos.insert(0, Info::Human(context));
+		self
+	}
+
+	/// Transform all human context messages in place, newest first, e.g. for redaction or
+	/// localization before displaying the error. Locations and attachments are left untouched.
+	///
+	/// ```
+	/// # use neuer_error::NeuErr;
+	/// let error = NeuErr::new("Failed reading /home/user/secret.txt")
+	/// 	.map_contexts(|message| *message = message.replace("/home/user", "~").into());
+	/// assert_eq!(error.message(), Some("Failed reading ~/secret.txt"));
+	/// ```
+	#[must_use]
+	#[inline]
+	pub fn map_contexts<F>(mut self, f: F) -> Self
+	where
+		F: FnMut(&mut Cow<'static, str>),
+	{
+		self.0.map_contexts_in_place(f);
+		self
+	}
+
+	/// Set the source error of the error. An existing source error is replaced and dropped, as
+	/// the new source error cannot be linked to it. The conversion location and cloneability of the
+	/// previous source are reset.
+	#[must_use]
+	#[inline]
+	pub fn with_source<E>(mut self, source: E) -> Self
+	where
+		E: ErrorSendSync + 'static,
+	{
+		self.0.set_source(source);
+		self
+	}
+
+	/// Add human context to the error via a fallible closure, e.g. resolving an ID to a name.
+	///
+	/// If the closure returns `None`, the error is left unchanged.
+	#[track_caller]
+	#[must_use]
+	#[inline]
+	pub fn context_try_with<F, C>(self, context_fn: F) -> Self
+	where
+		F: FnOnce() -> Option<C>,
+		C: Into<Cow<'static, str>>,
+	{
+		match context_fn() {
+			Some(context) => self.context(context),
+			None => self,
+		}
+	}
+
+	/// Add human context of a numbered step in a pipeline to the error, formatted like
+	/// `"step 2/5: {context}"`.
+	#[track_caller]
+	#[must_use]
+	#[inline]
+	pub fn context_step<C>(self, step: usize, total: usize, context: C) -> Self
+	where
+		C: Display,
+	{
+		self.context(format!("step {step}/{total}: {context}"))
+	}
+
+	/// Add machine context to the error.
+	///
+	/// This will not override existing attachments. If you want to replace and override any
+	/// existing attachments of the same type, use `attach_override` instead.
+	#[must_use]
+	#[inline]
+	pub fn attach<C>(mut self, context: C) -> Self
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.0.push_attachment(context, &AttachmentHooks::NONE);
+		self
+	}
+
+	/// Add multiple machine contexts of the same type to the error, in iteration order. So the
+	/// last item is the newest attachment.
+	#[must_use]
+	#[inline]
+	pub fn attach_all<I, C>(mut self, items: I) -> Self
+	where
+		I: IntoIterator<Item = C>,
+		C: AnyDebugSendSync + 'static,
+	{
+		self.0.infos.extend(items.into_iter().map(|item| Info::Machine(MachineInfo::new(item))));
+		self
+	}
+
+	/// Add cloneable machine context to the error, which is cloned in [`NeuErr::try_clone`].
+	/// Other attachments are not cloneable.
+	#[must_use]
+	#[inline]
+	pub fn attach_cloneable<C>(self, context: C) -> Self
+	where
+		C: CloneableAny + 'static,
+	{
+		self.attach_with_hooks(context, AttachmentHooks::cloneable::<C>())
+	}
+
+	/// Set machine context in the error.
+	///
+	/// This will override existing attachments of the same type. If you want to add attachments of
+	/// the same type, use `attach` instead.
+	#[must_use]
+	pub fn attach_override<C>(mut self, context: C) -> Self
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.0.override_attachment(context);
+		self
+	}
+
+	/// Add machine context to the error, which is also displayed when formatting the error.
+	///
+	/// In the multi-line format, the attachment is formatted with the alternate flag (`"{:#}"`),
+	/// so it can render in an expanded form. In the compact single-line format, it is formatted
+	/// normally.
+	#[must_use]
+	#[inline]
+	pub fn attach_display<C>(mut self, context: C) -> Self
+	where
+		C: AnyDebugSendSync + Display + 'static,
+	{
+		self.0.push_attachment(context, AttachmentHooks::displayed::<C>());
+		self
+	}
+
+	/// Add machine context to the error, which is handled by the given hooks.
+	#[inline]
+	pub(crate) fn attach_with_hooks<C>(
+		mut self,
+		context: C,
+		hooks: &'static AttachmentHooks,
+	) -> Self
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.0.push_attachment(context, hooks);
+		self
+	}
+
+	/// Add machine context to the error via a closure, only if there is no attachment of the same
+	/// type yet.
+	///
+	/// This is meant for expensive context, which multiple layers might want to attach: The closure
+	/// is called at most once over all calls, so only the first layer computes and attaches it.
+	#[must_use]
+	#[inline]
+	pub fn attach_lazy_once<C, F>(self, context_fn: F) -> Self
+	where
+		F: FnOnce() -> C,
+		C: AnyDebugSendSync + 'static,
+	{
+		if self.attachment::<C>().is_some() { self } else { self.attach(context_fn()) }
+	}
+
+	/// Replace the value of the newest machine context attachment of the given type and return the
+	/// previous value. If there is no attachment of this type yet, the value is attached and `None`
+	/// is returned.
+	///
+	/// This is the get-and-set primitive when using attachments to store state.
+	#[inline]
+	pub fn swap_attachment<C>(&mut self, value: C) -> Option<C>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.0.swap_attachment(value)
+	}
+
+	/// Remove all machine context attachments of the given type, e.g. to scrub sensitive
+	/// information before logging the error.
+	#[must_use]
+	#[inline]
+	pub fn remove_attachment<C>(mut self) -> Self
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.0.remove_all_attachments::<C>();
+		self
+	}
+
+	/// Add machine context of a [`Sensitive`](crate::Sensitive) type to the error, which is
+	/// removed via [`redact_sensitive`](Self::redact_sensitive), e.g. before logging the error.
+	#[must_use]
+	#[inline]
+	pub fn attach_sensitive<C>(mut self, context: C) -> Self
+	where
+		C: crate::Sensitive,
+	{
+		self.0.push_attachment(context, &AttachmentHooks::SENSITIVE);
+		self
+	}
+
+	/// Add machine context of an [`Attachment`](crate::Attachment) type to the error, which is
+	/// serialized and logged under its registered name. It is serialized by value and cloned via
+	/// [`try_clone`](Self::try_clone), if opted into via `#[attachment(serialize, clone)]`.
+	#[must_use]
+	#[inline]
+	pub fn attach_named<C>(mut self, context: C) -> Self
+	where
+		C: crate::Attachment,
+	{
+		self.0.push_attachment(context, AttachmentHooks::named::<C>());
+		self
+	}
+
+	/// Remove all machine context attachments of the given type, e.g. secrets before logging the
+	/// error. Equivalent to [`remove_attachment`](Self::remove_attachment).
+	#[must_use]
+	#[inline]
+	pub fn redact<C>(self) -> Self
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.remove_attachment::<C>()
+	}
+
+	/// Remove all machine context attachments added via
+	/// [`attach_sensitive`](Self::attach_sensitive), regardless of their type.
+	///
+	/// ```
+	/// # use neuer_error::{NeuErr, Sensitive};
+	/// #[derive(Debug)]
+	/// struct Token(String);
+	/// impl Sensitive for Token {}
+	///
+	/// let error = NeuErr::new("Request failed").attach_sensitive(Token("secret".to_owned()));
+	/// assert!(error.attachment::<Token>().is_some());
+	/// let error = error.redact_sensitive();
+	/// assert!(error.attachment::<Token>().is_none());
+	/// ```
+	#[must_use]
+	#[inline]
+	pub fn redact_sensitive(mut self) -> Self {
+		self.0.remove_sensitive();
+		self
+	}
+
+	/// Remove the newest machine context attachment of the given type and return it.
+	#[must_use]
+	#[inline]
+	pub fn take_attachment<C>(mut self) -> (Self, Option<C>)
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		let taken = self.0.remove_newest_attachment();
+		(self, taken)
+	}
+
+	/// Convert the error into its newest machine context attachment of the given type, dropping
+	/// the rest of the error. Returns the error unchanged if there is no such attachment.
+	#[inline]
+	pub fn into_attachment<C>(self) -> Result<C, Self>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		match self.take_attachment() {
+			(_, Some(attachment)) => Ok(attachment),
+			(error, None) => Err(error),
+		}
+	}
+
+	/// Remove duplicate machine context attachments, keeping the newest of each.
+	///
+	/// Attachments are considered duplicates if they have the same type and the same `Debug`
+	/// output, as attachments are not required to implement `PartialEq`. This is a heuristic, so
+	/// make sure distinct values of your attachment types have distinct `Debug` output.
+	#[inline]
+	pub fn dedup_attachments(&mut self) {
+		self.0.dedup_attachments();
+	}
+
+	/// Remove consecutive duplicate human contexts with the same message and location, e.g. when
+	/// a retry loop adds the same context on every attempt. Attachments in between are kept and
+	/// do not interrupt the duplicates.
+	#[must_use]
+	#[inline]
+	pub fn dedup_contexts(mut self) -> Self {
+		self.0.remove_duplicate_contexts();
+		self
+	}
+
+	/// Get an iterator over the human context infos.
+	#[inline]
+	#[cfg_attr(not(test), expect(unused, reason = "For consistency"))]
+	pub(crate) fn contexts(&self) -> impl Iterator<Item = &'_ HumanInfo> {
+		self.0.contexts()
+	}
+
+	/// Get the message of the newest human context.
+	#[must_use]
+	#[inline]
+	pub fn message(&self) -> Option<&str> {
+		self.messages().next()
+	}
+
+	/// Get an iterator over all human context messages, newest first.
+	#[inline]
+	pub fn messages(&self) -> impl Iterator<Item = &'_ str> {
+		self.0.contexts().map(|context| context.message.as_ref())
+	}
+
+	/// Get the location of the newest human context.
+	#[must_use]
+	#[inline]
+	pub fn location(&self) -> Option<&'static Location<'static>> {
+		self.locations().next()
+	}
+
+	/// Get an iterator over the locations of all human contexts, newest first.
+	#[inline]
+	pub fn locations(&self) -> impl Iterator<Item = &'static Location<'static>> {
+		self.0.contexts().map(|context| context.location)
+	}
+
+	/// Get an iterator over the machine context attachments of the given type.
+	#[inline]
+	pub fn attachments<C>(&self) -> impl Iterator<Item = &'_ C>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.0.attachments()
+	}
+
+	/// Get an iterator over the machine context attachments of the given type, also searching
+	/// nested errors in the source chain. Own attachments come first, then the attachments of
+	/// source errors that are [`NeuErr`]s, starting with the immediate source.
+	#[inline]
+	pub fn attachments_in_chain<C>(&self) -> impl Iterator<Item = &'_ C>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		let nested = self.0.chain().filter_map(|err| err.downcast_ref::<NeuErrImpl>());
+		self.0.attachments().chain(nested.flat_map(NeuErrImpl::attachments))
+	}
+
+	/// Get the machine context attachment of the given type, also searching nested errors in the
+	/// source chain. The match closest to the top error is returned, see
+	/// [`NeuErr::attachments_in_chain`].
+	#[must_use]
+	#[inline]
+	pub fn attachment_in_chain<C>(&self) -> Option<&C>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.attachments_in_chain().next()
+	}
+
+	/// Get the `Display` output of the newest machine context attachment of the given type.
+	#[must_use]
+	#[inline]
+	pub fn attachment_to_string<C>(&self) -> Option<String>
+	where
+		C: AnyDebugSendSync + Display + 'static,
+	{
+		self.attachment::<C>().map(ToString::to_string)
+	}
+
+	/// Get an iterator over at most the `n` newest machine context attachments of the given type,
+	/// e.g. to keep the output of many accumulated attachments bounded.
+	#[inline]
+	pub fn attachments_first<C>(&self, n: usize) -> impl Iterator<Item = &'_ C>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.attachments().take(n)
+	}
+
+	/// Get the machine context attachment of the given type.
+	#[must_use]
+	#[inline]
+	pub fn attachment<C>(&self) -> Option<&C>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.0.attachment()
+	}
+
+	/// Get a mutable iterator over the machine context attachments of the given type.
+	#[inline]
+	pub fn attachments_mut<C>(&mut self) -> impl Iterator<Item = &'_ mut C>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.0.attachments_mut()
+	}
+
+	/// Get mutable access to the machine context attachment of the given type.
+	#[must_use]
+	#[inline]
+	pub fn attachment_mut<C>(&mut self) -> Option<&mut C>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.0.attachment_mut()
+	}
+
+	/// Remove and yield the machine context attachments of the given type, oldest first.
+	///
+	/// The iterator is lazy: attachments are only removed as they are yielded, so stopping early
+	/// keeps the remaining ones in the error.
+	#[inline]
+	pub fn drain_attachments<C>(&mut self) -> impl Iterator<Item = C>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.0.drain_attachments()
+	}
+
+	/// Get the length of the source error chain, i.e. the number of source errors. It is computed
+	/// once when the source is set, so querying it is cheap.
+	#[must_use]
+	#[inline]
+	pub const fn source_chain_len(&self) -> usize {
+		self.0.source_chain_len()
+	}
+
+	/// Get the number of human contexts.
+	#[must_use]
+	#[inline]
+	pub fn context_count(&self) -> usize {
+		self.0.context_count()
+	}
+
+	/// Get the number of machine context attachments, of all types.
+	#[must_use]
+	#[inline]
+	pub fn attachment_count(&self) -> usize {
+		self.0.attachment_count()
+	}
+
+	/// Whether the error has a source error.
+	#[must_use]
+	#[inline]
+	pub const fn has_source(&self) -> bool {
+		self.0.has_source()
+	}
+
+	/// Whether the error has neither human contexts, nor attachments, nor a source error, e.g.
+	/// when created via `NeuErr::default()`.
+	#[must_use]
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Get the location where the source error was converted into this error, if it was recorded,
+	/// e.g. by the `?` operator.
+	#[must_use]
+	#[inline]
+	pub fn source_location(&self) -> Option<&'static Location<'static>> {
+		self.0.source_location()
+	}
+
+	/// Get the backtrace captured at error creation. It is only captured if enabled via the
+	/// `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables, see
+	/// [`Backtrace::capture`].
+	#[cfg(feature = "backtrace")]
+	#[must_use]
+	#[inline]
+	pub fn backtrace(&self) -> Option<&Backtrace> {
+		self.0.backtrace()
+	}
+
+	/// Get the source error.
+	#[must_use]
+	#[inline]
+	pub fn source(&self) -> Option<&(dyn ErrorSendSync + 'static)> {
+		self.0.source.as_deref()
+	}
+
+	/// Get the immediate source error as concrete type, if it is of this type.
+	#[must_use]
+	#[inline]
+	pub fn downcast_source_ref<E>(&self) -> Option<&E>
+	where
+		E: Error + 'static,
+	{
+		#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+		self.source().and_then(|source| (source as &(dyn Error + 'static)).downcast_ref())
+	}
+
+	/// Convert the error into its immediate source error as concrete type, if it is of this type.
+	/// The remaining context information is dropped. Returns the error unchanged otherwise.
+	#[inline]
+	pub fn downcast_source<E>(mut self) -> Result<E, Self>
+	where
+		E: Error + 'static,
+	{
+		// Check the type first, so the source is only taken if the downcast succeeds.
+		if self.downcast_source_ref::<E>().is_some()
+			&& let Some(source) = self.0.source.take()
+		{
+			let source: Box<dyn Error> = source;
+			if let Ok(source) = source.downcast() {
+				return Ok(*source);
+			}
+		}
+		Err(self)
+	}
+
+	/// Get an iterator over the source error chain, starting with the immediate source and ending
+	/// with the innermost (root) cause.
+	#[inline]
+	pub fn chain(&self) -> impl Iterator<Item = &'_ (dyn Error + 'static)> {
+		self.0.chain()
+	}
+
+	/// Get an iterator over the source error chain, starting with the innermost (root) cause and
+	/// ending with the immediate source.
+	///
+	/// Source errors only link to their inner errors, so this has to collect the whole chain into a
+	/// `Vec` first, which allocates.
+	#[inline]
+	pub fn chain_rev(&self) -> impl Iterator<Item = &'_ (dyn Error + 'static)> {
+		self.0.chain().collect::<Vec<_>>().into_iter().rev()
+	}
+
+	/// Get the innermost (root) cause of the source error chain, e.g. to group errors by their
+	/// ultimate cause. Returns `None` if there is no source error.
+	#[must_use]
+	#[inline]
+	pub fn root_cause(&self) -> Option<&(dyn Error + 'static)> {
+		self.0.root_cause()
+	}
+
+	/// Get a [`Report`] of the error to format it with custom formatting options.
+	#[inline]
+	pub const fn as_report(&self) -> Report<'_> {
+		self.0.as_report()
+	}
+
+	/// Get a [`Report`] of the error with the given formatting options, e.g. to hide locations in
+	/// user-facing logs.
+	#[inline]
+	pub const fn display_with(&self, options: &FormatOptions) -> Report<'_> {
+		self.as_report().with_options(*options)
+	}
+
+	/// Get a [`Report`] preset for production log lines: single-line format, locations as
+	/// `file.rs:line` and no colors.
+	#[inline]
+	pub const fn log_line(&self) -> Report<'_> {
+		self.as_report().compact().location_style(LocationStyle::FileLine).without_colors()
+	}
+
+	/// Convert the error into its message, if it consists of exactly one human context and has no
+	/// source error. Attachments are discarded. Otherwise, the unchanged error is returned.
+	///
+	/// This helps checking the invariant of code paths that should only produce simple errors, but
+	/// might accidentally accumulate context.
+	pub fn into_single_message(mut self) -> Result<Cow<'static, str>, Self> {
+		let mut human =
+			self.0.infos.iter().enumerate().filter(|(_, info)| matches!(info, Info::Human(_)));
+		if let (Some((index, _)), None) = (human.next(), human.next())
+			&& self.0.source.is_none()
+			&& let Info::Human(info) = self.0.infos.swap_remove(index)
+		{
+			return Ok(info.message);
+		}
+		Err(self)
+	}
+
+	/// Convert the error into its machine context attachments, newest first like
+	/// [`attachments`](Self::attachments), e.g. to migrate them into another error type. The human
+	/// contexts and the source error are dropped.
+	///
+	/// The attachments can be downcast to their concrete types via `Box<dyn Any>`:
+	///
+	/// ```
+	/// # use neuer_error::NeuErr;
+	/// # use std::any::Any;
+	/// let error = NeuErr::new("test").attach(404_u16).attach("text");
+	/// let attachments = error.into_attachments().collect::<Vec<_>>();
+	/// let status: Box<dyn Any> = attachments.into_iter().nth(1).unwrap();
+	/// assert_eq!(status.downcast::<u16>().ok().map(|status| *status), Some(404));
+	/// ```
+	pub fn into_attachments(self) -> impl Iterator<Item = Box<dyn AnyDebugSendSync>> {
+		self.0.infos.into_iter().rev().filter_map(|info| match info {
+			Info::Machine(ctx) => Some(ctx.attachment),
+			Info::Human(_) => None,
+		})
+	}
+
+	/// Convert the error into its human context messages with their locations, newest first like
+	/// [`messages`](Self::messages). The attachments and the source error are dropped.
+	pub fn into_contexts(
+		self,
+	) -> impl Iterator<Item = (Cow<'static, str>, &'static Location<'static>)> {
+		self.0.infos.into_iter().rev().filter_map(|info| match info {
+			Info::Human(ctx) => Some((ctx.message, ctx.location)),
+			Info::Machine(_) => None,
+		})
+	}
+
+	/// Clone the error, if all attachments and the source error are cloneable. Attachments are
+	/// cloneable if added via [`NeuErr::attach_cloneable`], source errors if added via
+	/// [`NeuErr::new_with_cloneable_source`] or [`NeuErr::from_cloneable_source`]. A captured
+	/// backtrace is not cloned.
+	#[must_use]
+	#[inline]
+	pub fn try_clone(&self) -> Option<Self> {
+		self.0.try_clone().map(NeuErrImpl::wrap)
+	}
+
+	/// Unwrap this error into a [`NeuErrImpl`] that implements [`Error`]. Note however, that it
+	/// does not offer all of the functionality and might be unwieldy for other general purposes
+	/// than interfacing with other error types.
+	#[must_use]
+	#[inline]
+	pub fn into_error(self) -> NeuErrImpl {
+		*self.0
+	}
+}
+
+impl NeuErrImpl {
+	/// Create a new error with the given infos and source error.
+	fn from_parts<E>(infos: Infos, source: E) -> Self
+	where
+		E: ErrorSendSync + 'static,
+	{
+		#[cfg(feature = "timestamp")]
+		let infos = with_created_at(infos);
+		#[cfg(feature = "thread-info")]
+		let infos = with_thread_info(infos);
+		let source_depth =
+			1 + ::core::iter::successors(source.source(), |&err| err.source()).count();
+		Self {
+			infos,
+			source: Some(Box::new(source)),
+			source_depth,
+			source_location: None,
+			source_clone: None,
+			#[cfg(feature = "backtrace")]
+			backtrace: capture_backtrace(),
+		}
+	}
+
+	/// Wrap this error back into a [`NeuErr`] that offers all of the functionality.
+	///
+	/// Not `const`, since [`NeuErr`] boxes the error to stay a single pointer wide.
+	#[must_use]
+	#[inline]
+	pub fn wrap(self) -> NeuErr {
+		NeuErr(Box::new(self))
+	}
+
+	/// Get a [`Report`] of the error to format it with custom formatting options.
+	#[inline]
+	pub const fn as_report(&self) -> Report<'_> {
+		Report::new(self)
+	}
+
+	/// Clone the error, if all attachments and the source error are cloneable, see
+	/// [`NeuErr::try_clone`].
+	#[must_use]
+	pub fn try_clone(&self) -> Option<Self> {
+		let infos = self.infos.iter().map(Info::try_clone).collect::<Option<Infos>>()?;
+		let source = match &self.source {
+			Some(source) => Some((self.source_clone?)(source.as_ref())?),
+			None => None,
+		};
+		Some(Self {
+			infos,
+			source,
+			source_depth: self.source_depth,
+			source_location: self.source_location,
+			source_clone: self.source_clone,
+			#[cfg(feature = "backtrace")]
+			backtrace: None,
+		})
+	}
+
+	/// Add human context to the error.
+	#[track_caller]
+	#[must_use]
+	#[inline]
+	pub fn context<C>(mut self, context: C) -> Self
+	where
+		C: Into<Cow<'static, str>>,
+	{
+		let context = HumanInfo { message: context.into(), location: Location::caller() };
+		self.infos.push(Info::Human(context));
+		self
+	}
+
+	/// Add human context to the error as its oldest context, so that it is rendered below all
+	/// existing contexts, but still above the source error. See [`NeuErr::context_inner`].
+	#[track_caller]
+	#[must_use]
+	#[inline]
+	pub fn context_inner<C>(mut self, context: C) -> Self
+	where
+		C: Into<Cow<'static, str>>,
+	{
+		let context = HumanInfo { message: context.into(), location: Location::caller() };
+		self.infos.insert(0, Info::Human(context));
+		self
+	}
+
+	/// Transform all human context messages in place, newest first, e.g. for redaction or
+	/// localization before displaying the error. Locations and attachments are left untouched.
+	#[must_use]
+	pub fn map_contexts<F>(mut self, f: F) -> Self
+	where
+		F: FnMut(&mut Cow<'static, str>),
+	{
+		self.map_contexts_in_place(f);
+		self
+	}
+
+	/// Transform all human context messages in place, newest first.
+	fn map_contexts_in_place<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&mut Cow<'static, str>),
+	{
+		for info in self.infos.iter_mut().rev() {
+			if let Info::Human(context) = info {
+				f(&mut context.message);
+			}
+		}
+	}
+
+	/// Set the source error of the error. An existing source error is replaced and dropped, as
+	/// the new source error cannot be linked to it. The conversion location and cloneability of the
+	/// previous source are reset.
+	#[must_use]
+	#[inline]
+	pub fn with_source<E>(mut self, source: E) -> Self
+	where
+		E: ErrorSendSync + 'static,
+	{
+		self.set_source(source);
+		self
+	}
+
+	/// Set the source error of the error, resetting the conversion location and cloneability.
+	fn set_source<E>(&mut self, source: E)
+	where
+		E: ErrorSendSync + 'static,
+	{
+		self.source_depth =
+			1 + ::core::iter::successors(source.source(), |&err| err.source()).count();
+		self.source = Some(Box::new(source));
+		self.source_location = None;
+		self.source_clone = None;
+	}
+
+	/// Add machine context to the error.
+	///
+	/// This will not override existing attachments. If you want to replace and override any
+	/// existing attachments of the same type, use `attach_override` instead.
+	#[must_use]
+	#[inline]
+	pub fn attach<C>(mut self, context: C) -> Self
 	where
 		C: AnyDebugSendSync + 'static,
 	{
-		self.0.attachments()
+		self.infos.push(Info::Machine(MachineInfo::new(context)));
+		self
 	}
 
-	/// Get the machine context attachment of the given type.
+	/// Add multiple machine contexts of the same type to the error, in iteration order. So the
+	/// last item is the newest attachment.
 	#[must_use]
 	#[inline]
-	pub fn attachment<C>(&self) -> Option<&C>
+	pub fn attach_all<I, C>(mut self, items: I) -> Self
 	where
+		I: IntoIterator<Item = C>,
 		C: AnyDebugSendSync + 'static,
 	{
-		self.0.attachment()
-	}
-
-	/// Get the source error.
-	#[must_use]
-	#[inline]
-	pub fn source(&self) -> Option<&(dyn ErrorSendSync + 'static)> {
-		self.0.source.as_deref()
+		self.infos.extend(items.into_iter().map(|item| Info::Machine(MachineInfo::new(item))));
+		self
 	}
 
-	/// Unwrap this error into a [`NeuErrImpl`] that implements [`Error`]. Note however, that it
-	/// does not offer all of the functionality and might be unwieldy for other general purposes
-	/// than interfacing with other error types.
+	/// Add cloneable machine context to the error, which is cloned in [`NeuErr::try_clone`].
+	/// Other attachments are not cloneable.
 	#[must_use]
 	#[inline]
-	pub fn into_error(self) -> NeuErrImpl {
-		self.0
+	pub fn attach_cloneable<C>(self, context: C) -> Self
+	where
+		C: CloneableAny + 'static,
+	{
+		self.attach_with_hooks(context, AttachmentHooks::cloneable::<C>())
 	}
-}
 
-impl NeuErrImpl {
-	/// Wrap this error back into a [`NeuErr`] that offers all of the functionality.
+	/// Add machine context to the error, which is also displayed when formatting the error.
+	///
+	/// In the multi-line format, the attachment is formatted with the alternate flag (`"{:#}"`),
+	/// so it can render in an expanded form. In the compact single-line format, it is formatted
+	/// normally.
 	#[must_use]
 	#[inline]
-	pub const fn wrap(self) -> NeuErr {
-		NeuErr(self)
+	pub fn attach_display<C>(self, context: C) -> Self
+	where
+		C: AnyDebugSendSync + Display + 'static,
+	{
+		self.attach_with_hooks(context, AttachmentHooks::displayed::<C>())
 	}
 
-	/// Add human context to the error.
-	#[track_caller]
-	#[must_use]
+	/// Add machine context to the error, which is handled by the given hooks.
 	#[inline]
-	pub fn context<C>(mut self, context: C) -> Self
+	pub(crate) fn attach_with_hooks<C>(
+		mut self,
+		context: C,
+		hooks: &'static AttachmentHooks,
+	) -> Self
 	where
-		C: Into<Cow<'static, str>>,
+		C: AnyDebugSendSync + 'static,
 	{
-		let context = HumanInfo { message: context.into(), location: Location::caller() };
-		self.infos.push(Info::Human(context));
+		self.push_attachment(context, hooks);
 		self
 	}
 
-	/// Add machine context to the error.
-	///
-	/// This will not override existing attachments. If you want to replace and override any
-	/// existing attachments of the same type, use `attach_override` instead.
-	#[must_use]
+	/// Add machine context to the error in place, which is handled by the given hooks.
 	#[inline]
-	pub fn attach<C>(mut self, context: C) -> Self
+	pub(crate) fn push_attachment<C>(&mut self, context: C, hooks: &'static AttachmentHooks)
 	where
 		C: AnyDebugSendSync + 'static,
 	{
-		let context = MachineInfo { attachment: Box::new(context) };
-		self.infos.push(Info::Machine(context));
-		self
+		self.infos.push(Info::Machine(MachineInfo::with_hooks(context, hooks)));
 	}
 
 	/// Set machine context in the error.
@@ -313,7 +1623,16 @@ impl NeuErrImpl {
 	/// This will override existing attachments of the same type. If you want to add attachments of
 	/// the same type, use `attach` instead.
 	#[must_use]
-	pub fn attach_override<C>(mut self, mut context: C) -> Self
+	pub fn attach_override<C>(mut self, context: C) -> Self
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.override_attachment(context);
+		self
+	}
+
+	/// Replace all machine context attachments of the same type by the given one, in place.
+	fn override_attachment<C>(&mut self, mut context: C)
 	where
 		C: AnyDebugSendSync + 'static,
 	{
@@ -339,17 +1658,263 @@ impl NeuErrImpl {
 		});
 		if !inserted {
 			// No existing attachment of the same type was found to be replaced, so add a new one.
-			self.infos.push(Info::Machine(MachineInfo { attachment: Box::new(context) }));
+			self.infos.push(Info::Machine(MachineInfo::new(context)));
+		}
+	}
+
+	/// Replace the value of the newest machine context attachment of the given type and return the
+	/// previous value. If there is no attachment of this type yet, the value is attached and `None`
+	/// is returned.
+	///
+	/// This is the get-and-set primitive when using attachments to store state.
+	pub fn swap_attachment<C>(&mut self, mut value: C) -> Option<C>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		if let Some(existing) = self.attachment_mut::<C>() {
+			core::mem::swap(existing, &mut value);
+			Some(value)
+		} else {
+			self.infos.push(Info::Machine(MachineInfo::new(value)));
+			None
+		}
+	}
+
+	/// Remove and yield the machine context attachments of the given type, oldest first.
+	///
+	/// The iterator is lazy: attachments are only removed as they are yielded, so stopping early
+	/// keeps the remaining ones in the error.
+	pub fn drain_attachments<C>(&mut self) -> impl Iterator<Item = C>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		let is_attachment = |info: &mut Info| info.is_attachment::<C>();
+		#[cfg(not(feature = "smallvec"))]
+		let drained = self.infos.extract_if(.., is_attachment);
+		// `extract_if` is not available for inline infos, so remove them one by one.
+		#[cfg(feature = "smallvec")]
+		let drained = {
+			let infos = &mut self.infos;
+			let mut index = 0;
+			::core::iter::from_fn(move || {
+				while let Some(info) = infos.get_mut(index) {
+					if is_attachment(info) {
+						return Some(infos.remove(index));
+					}
+					index += 1;
+				}
+				None
+			})
+		};
+		drained.filter_map(|info| match info {
+			Info::Machine(ctx) => {
+				let attachment: Box<dyn Any> = ctx.attachment;
+				attachment.downcast().ok().map(|attachment| *attachment)
+			}
+			Info::Human(_) => None,
+		})
+	}
+
+	/// Remove all machine context attachments of the given type, e.g. to scrub sensitive
+	/// information before logging the error.
+	#[must_use]
+	#[inline]
+	pub fn remove_attachment<C>(mut self) -> Self
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.remove_all_attachments::<C>();
+		self
+	}
+
+	/// Remove all machine context attachments of the given type in place.
+	fn remove_all_attachments<C>(&mut self)
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.infos.retain(|info| !info.is_attachment::<C>());
+	}
+
+	/// Add machine context of a [`Sensitive`](crate::Sensitive) type to the error, which is
+	/// removed via [`redact_sensitive`](Self::redact_sensitive), e.g. before logging the error.
+	#[must_use]
+	#[inline]
+	pub fn attach_sensitive<C>(self, context: C) -> Self
+	where
+		C: crate::Sensitive,
+	{
+		self.attach_with_hooks(context, &AttachmentHooks::SENSITIVE)
+	}
+
+	/// Add machine context of an [`Attachment`](crate::Attachment) type to the error, which is
+	/// serialized and logged under its registered name.
+	#[must_use]
+	#[inline]
+	pub fn attach_named<C>(self, context: C) -> Self
+	where
+		C: crate::Attachment,
+	{
+		self.attach_with_hooks(context, AttachmentHooks::named::<C>())
+	}
+
+	/// Remove all machine context attachments of the given type, e.g. secrets before logging the
+	/// error. Equivalent to [`remove_attachment`](Self::remove_attachment).
+	#[must_use]
+	#[inline]
+	pub fn redact<C>(self) -> Self
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.remove_attachment::<C>()
+	}
+
+	/// Remove all machine context attachments added via
+	/// [`attach_sensitive`](Self::attach_sensitive), regardless of their type.
+	#[must_use]
+	pub fn redact_sensitive(mut self) -> Self {
+		self.remove_sensitive();
+		self
+	}
+
+	/// Remove all sensitive machine context attachments in place.
+	fn remove_sensitive(&mut self) {
+		self.infos.retain(|info| match info {
+			Info::Machine(ctx) => !ctx.hooks.sensitive,
+			Info::Human(_) => true,
+		});
+	}
+
+	/// Remove the newest machine context attachment of the given type and return it.
+	#[must_use]
+	pub fn take_attachment<C>(mut self) -> (Self, Option<C>)
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		let taken = self.remove_newest_attachment();
+		(self, taken)
+	}
+
+	/// Remove the newest machine context attachment of the given type in place and return it.
+	fn remove_newest_attachment<C>(&mut self) -> Option<C>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+		let index = self.infos.iter().rposition(|info| match info {
+			Info::Machine(ctx) => (ctx.attachment.as_ref() as &(dyn Any + 'static)).is::<C>(),
+			Info::Human(_) => false,
+		});
+		index.and_then(|index| match self.infos.remove(index) {
+			Info::Machine(ctx) => {
+				let attachment: Box<dyn Any> = ctx.attachment;
+				attachment.downcast().ok().map(|attachment| *attachment)
+			}
+			Info::Human(_) => None,
+		})
+	}
+
+	/// Convert the error into its newest machine context attachment of the given type, dropping
+	/// the rest of the error. Returns the error unchanged if there is no such attachment.
+	#[cfg_attr(
+		feature = "smallvec",
+		allow(clippy::result_large_err, reason = "Inline infos, use the boxed `NeuErr` instead")
+	)]
+	#[inline]
+	pub fn into_attachment<C>(self) -> Result<C, Self>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		match self.take_attachment() {
+			(_, Some(attachment)) => Ok(attachment),
+			(error, None) => Err(error),
 		}
+	}
+
+	/// Remove duplicate machine context attachments, keeping the newest of each.
+	///
+	/// Attachments are considered duplicates if they have the same type and the same `Debug`
+	/// output, as attachments are not required to implement `PartialEq`. This is a heuristic, so
+	/// make sure distinct values of your attachment types have distinct `Debug` output.
+	pub fn dedup_attachments(&mut self) {
+		let mut seen: Vec<(TypeId, String)> = Vec::new();
+		// Decide from newest to oldest, so that the newest attachment is kept.
+		let mut keep = self
+			.infos()
+			.map(|info| match info {
+				Info::Machine(ctx) => {
+					#[expect(
+						trivial_casts,
+						reason = "Not that trivial as it seems? False positive"
+					)]
+					let type_id = (ctx.attachment.as_ref() as &(dyn Any + 'static)).type_id();
+					let key = (type_id, format!("{:?}", ctx.attachment));
+					let duplicate = seen.contains(&key);
+					if !duplicate {
+						seen.push(key);
+					}
+					!duplicate
+				}
+				Info::Human(_) => true,
+			})
+			.collect::<Vec<_>>();
+		self.infos.retain(|_| keep.pop().unwrap_or(true));
+	}
+
+	/// Remove consecutive duplicate human contexts with the same message and location, e.g. when
+	/// a retry loop adds the same context on every attempt. Attachments in between are kept and
+	/// do not interrupt the duplicates.
+	#[must_use]
+	pub fn dedup_contexts(mut self) -> Self {
+		self.remove_duplicate_contexts();
 		self
 	}
 
+	/// Remove consecutive duplicate human contexts in place.
+	fn remove_duplicate_contexts(&mut self) {
+		let mut previous = None;
+		let keep = self
+			.infos
+			.iter()
+			.map(|info| match info {
+				Info::Human(context) => {
+					let duplicate = previous == Some(context);
+					previous = Some(context);
+					!duplicate
+				}
+				Info::Machine(_) => true,
+			})
+			.collect::<Vec<_>>();
+		let mut keep = keep.into_iter();
+		self.infos.retain(|_| keep.next().unwrap_or(true));
+	}
+
 	/// Get an iterator over all context infos.
 	#[inline]
-	pub(crate) fn infos(&self) -> impl Iterator<Item = &'_ Info> {
+	pub(crate) fn infos(&self) -> impl DoubleEndedIterator<Item = &'_ Info> {
 		self.infos.iter().rev()
 	}
 
+	/// Get the only human context info, if the error consists of nothing else.
+	#[inline]
+	pub(crate) fn single_context(&self) -> Option<&HumanInfo> {
+		#[cfg(feature = "backtrace")]
+		if self.backtrace.is_some() {
+			return None;
+		}
+		match (self.infos.as_slice(), &self.source) {
+			([Info::Human(context)], None) => Some(context),
+			_ => None,
+		}
+	}
+
+	/// Get the backtrace captured at error creation, if any.
+	#[cfg(feature = "backtrace")]
+	#[must_use]
+	#[inline]
+	pub const fn backtrace(&self) -> Option<&Backtrace> {
+		self.backtrace.as_ref()
+	}
+
 	/// Get an iterator over the human context infos.
 	#[inline]
 	pub(crate) fn contexts(&self) -> impl Iterator<Item = &'_ HumanInfo> {
@@ -359,6 +1924,32 @@ impl NeuErrImpl {
 		})
 	}
 
+	/// Get the message of the newest human context.
+	#[must_use]
+	#[inline]
+	pub fn message(&self) -> Option<&str> {
+		self.messages().next()
+	}
+
+	/// Get an iterator over all human context messages, newest first.
+	#[inline]
+	pub fn messages(&self) -> impl Iterator<Item = &'_ str> {
+		self.contexts().map(|context| context.message.as_ref())
+	}
+
+	/// Get the location of the newest human context.
+	#[must_use]
+	#[inline]
+	pub fn location(&self) -> Option<&'static Location<'static>> {
+		self.locations().next()
+	}
+
+	/// Get an iterator over the locations of all human contexts, newest first.
+	#[inline]
+	pub fn locations(&self) -> impl Iterator<Item = &'static Location<'static>> {
+		self.contexts().map(|context| context.location)
+	}
+
 	/// Get an iterator over the machine context attachments of the given type.
 	#[inline]
 	pub fn attachments<C>(&self) -> impl Iterator<Item = &'_ C>
@@ -384,12 +1975,130 @@ impl NeuErrImpl {
 	{
 		self.attachments().next()
 	}
+
+	/// Get a mutable iterator over the machine context attachments of the given type.
+	#[inline]
+	pub fn attachments_mut<C>(&mut self) -> impl Iterator<Item = &'_ mut C>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+		self.infos
+			.iter_mut()
+			.rev()
+			.filter_map(|info| match info {
+				Info::Machine(info) => Some(info),
+				_ => None,
+			}) // Catch the newest attachment first.
+			.map(|ctx| ctx.attachment.as_mut() as &mut (dyn Any + 'static))
+			.filter_map(|ctx| ctx.downcast_mut())
+	}
+
+	/// Get mutable access to the machine context attachment of the given type.
+	#[must_use]
+	#[inline]
+	pub fn attachment_mut<C>(&mut self) -> Option<&mut C>
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.attachments_mut().next()
+	}
+
+	/// Get the length of the source error chain, i.e. the number of source errors. It is computed
+	/// once when the source is set, so querying it is cheap.
+	#[must_use]
+	#[inline]
+	pub const fn source_chain_len(&self) -> usize {
+		self.source_depth
+	}
+
+	/// Get the number of human contexts.
+	#[must_use]
+	#[inline]
+	pub fn context_count(&self) -> usize {
+		self.contexts().count()
+	}
+
+	/// Get the number of machine context attachments, of all types.
+	#[must_use]
+	#[inline]
+	pub fn attachment_count(&self) -> usize {
+		self.infos.len() - self.context_count()
+	}
+
+	/// Whether the error has a source error.
+	#[must_use]
+	#[inline]
+	pub const fn has_source(&self) -> bool {
+		self.source.is_some()
+	}
+
+	/// Remove the attachments added automatically at error creation, to compare errors in tests
+	/// regardless of the enabled features.
+	#[cfg(test)]
+	pub(crate) fn remove_automatic(&mut self) {
+		self.infos.retain(|info| match info {
+			Info::Machine(ctx) => !ctx.is_automatic(),
+			Info::Human(_) => true,
+		});
+	}
+
+	/// Whether the error has neither human contexts, nor attachments, nor a source error, e.g.
+	/// when created via `NeuErrImpl::default()`.
+	#[must_use]
+	#[inline]
+	#[cfg_attr(
+		not(feature = "smallvec"),
+		expect(clippy::missing_const_for_fn, reason = "Not const with inline infos")
+	)]
+	pub fn is_empty(&self) -> bool {
+		self.infos.is_empty() && !self.has_source()
+	}
+
+	/// Get the location where the source error was converted into this error, if it was recorded.
+	#[inline]
+	pub(crate) const fn source_location(&self) -> Option<&'static Location<'static>> {
+		self.source_location
+	}
+
+	/// Get an iterator over the source error chain, starting with the immediate source and ending
+	/// with the innermost (root) cause.
+	#[inline]
+	pub fn chain(&self) -> impl Iterator<Item = &'_ (dyn Error + 'static)> {
+		#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+		let source = self.source.as_deref().map(|e| e as &(dyn Error + 'static));
+		::core::iter::successors(source, |&err| err.source())
+	}
+
+	/// Get the innermost (root) cause of the source error chain, e.g. to group errors by their
+	/// ultimate cause. Returns `None` if there is no source error.
+	#[must_use]
+	#[inline]
+	pub fn root_cause(&self) -> Option<&(dyn Error + 'static)> {
+		self.chain().last()
+	}
+}
+
+impl FromIterator<NeuErr> for NeuErr {
+	/// Aggregate the errors into one error with [`MultipleErrors`] source. A single error is
+	/// returned unchanged, no errors result in an unknown error.
+	fn from_iter<I>(iter: I) -> Self
+	where
+		I: IntoIterator<Item = NeuErr>,
+	{
+		let mut errors = iter.into_iter().collect::<Vec<_>>();
+		if errors.len() <= 1 {
+			return errors.pop().unwrap_or_default();
+		}
+		let errors = errors.into_iter().map(IntoSourceError::into_source_error);
+		Self::from_source(MultipleErrors(errors.collect()))
+	}
 }
 
 impl From<NeuErr> for NeuErrImpl {
 	#[inline]
 	fn from(err: NeuErr) -> Self {
-		err.0
+		*err.0
 	}
 }
 
@@ -398,9 +2107,33 @@ impl<E> From<E> for NeuErr
 where
 	E: ErrorSendSync + 'static,
 {
+	/// Convert the source error, recording the location of the conversion, e.g. the `?`.
+	#[track_caller]
 	#[inline]
 	fn from(err: E) -> Self {
-		Self::from_source(err)
+		Self::from_source_at(err, Location::caller())
+	}
+}
+
+impl<E> From<Tracked<E>> for NeuErr
+where
+	E: ErrorSendSync + 'static,
+{
+	#[track_caller]
+	#[inline]
+	fn from(Tracked(err): Tracked<E>) -> Self {
+		Self::from_source_at(err, Location::caller())
+	}
+}
+
+impl<M> From<Msg<M>> for NeuErr
+where
+	M: Into<Cow<'static, str>>,
+{
+	#[track_caller]
+	#[inline]
+	fn from(Msg(message): Msg<M>) -> Self {
+		Self::new(message)
 	}
 }
 
@@ -415,7 +2148,7 @@ impl Error for NeuErrImpl {
 impl AsRef<dyn Error> for NeuErr {
 	#[inline]
 	fn as_ref(&self) -> &(dyn Error + 'static) {
-		&self.0
+		&*self.0
 	}
 }
 
@@ -423,7 +2156,7 @@ impl AsRef<dyn Error> for NeuErr {
 impl AsRef<dyn Error + Send> for NeuErr {
 	#[inline]
 	fn as_ref(&self) -> &(dyn Error + Send + 'static) {
-		&self.0
+		&*self.0
 	}
 }
 
@@ -431,7 +2164,7 @@ impl AsRef<dyn Error + Send> for NeuErr {
 impl AsRef<dyn Error + Send + Sync> for NeuErr {
 	#[inline]
 	fn as_ref(&self) -> &(dyn Error + Send + Sync + 'static) {
-		&self.0
+		&*self.0
 	}
 }
 
@@ -459,20 +2192,73 @@ impl From<NeuErr> for Box<dyn Error + Send + Sync> {
 }
 
 
+#[cfg(feature = "std")]
+impl NeuErr {
+	/// Get the `io::ErrorKind` of the error: The newest attached `io::ErrorKind`, otherwise the
+	/// kind of the first `io::Error` in the source chain, if any.
+	#[must_use]
+	pub fn io_error_kind(&self) -> Option<std::io::ErrorKind> {
+		self.attachment::<std::io::ErrorKind>().copied().or_else(|| {
+			self.0
+				.chain()
+				.find_map(|source| source.downcast_ref::<std::io::Error>())
+				.map(std::io::Error::kind)
+		})
+	}
+
+	/// Run the closure, converting a panic into an error with the panic message and the
+	/// [`Panicked`](crate::Panicked) marker attached, e.g. to isolate untrusted callbacks. Panic
+	/// payloads other than strings result in a generic message.
+	///
+	/// The panic hook still runs as usual, e.g. printing the panic to stderr.
+	#[track_caller]
+	pub fn catch_unwind<F, R>(f: F) -> crate::Result<R>
+	where
+		F: FnOnce() -> R + ::core::panic::UnwindSafe,
+	{
+		// No closure via `map_err`, so the caller's location is tracked.
+		let payload = match std::panic::catch_unwind(f) {
+			Ok(value) => return Ok(value),
+			Err(payload) => payload,
+		};
+		let message = match payload.downcast::<&'static str>() {
+			Ok(message) => Cow::Borrowed(*message),
+			Err(payload) => match payload.downcast::<String>() {
+				Ok(message) => Cow::Owned(*message),
+				Err(_) => Cow::Borrowed("Panicked with non-string payload"),
+			},
+		};
+		Err(Self::new(message).attach(crate::Panicked))
+	}
+}
+
+#[cfg(all(feature = "std", feature = "send", feature = "sync"))]
+impl From<NeuErr> for std::io::Error {
+	/// Convert the error into an `io::Error` wrapping it. The kind is selected via
+	/// [`NeuErr::io_error_kind`], falling back to `ErrorKind::Other`.
+	#[inline]
+	fn from(error: NeuErr) -> Self {
+		let kind = error.io_error_kind().unwrap_or(std::io::ErrorKind::Other);
+		Self::new(kind, error.into_error())
+	}
+}
+
 #[cfg(feature = "std")]
 impl std::process::Termination for NeuErr {
 	#[inline]
 	fn report(self) -> std::process::ExitCode {
-		std::process::Termination::report(self.0)
+		std::process::Termination::report(*self.0)
 	}
 }
 
 #[cfg(feature = "std")]
 impl std::process::Termination for NeuErrImpl {
+	/// Returns the attached `ExitCode`, or the exit code of the attached
+	/// [`Severity`](crate::Severity), or `ExitCode::FAILURE`.
 	#[inline]
 	fn report(self) -> std::process::ExitCode {
-		self.attachment::<std::process::ExitCode>()
-			.copied()
-			.unwrap_or(std::process::ExitCode::FAILURE)
+		self.attachment::<std::process::ExitCode>().copied().unwrap_or_else(|| {
+			self.attachment::<crate::Severity>().copied().unwrap_or_default().exit_code()
+		})
 	}
 }
@@ -1,5 +1,6 @@
 //! Feature selection configuration of types.
 
+use ::alloc::boxed::Box;
 use ::core::{any::Any, error::Error, fmt::Debug};
 
 
@@ -7,38 +8,60 @@ use ::core::{any::Any, error::Error, fmt::Debug};
 #[cfg(feature = "send")]
 pub trait PotentiallySend: Send {}
 #[cfg(feature = "send")]
-impl<T: Send> PotentiallySend for T {}
+impl<T: Send + ?Sized> PotentiallySend for T {}
 /// Send trait, if feature is enabled, otherwise nothing.
 #[cfg(not(feature = "send"))]
 pub trait PotentiallySend {}
 #[cfg(not(feature = "send"))]
-impl<T> PotentiallySend for T {}
+impl<T: ?Sized> PotentiallySend for T {}
 
 /// Sync trait, if feature is enabled, otherwise nothing.
 #[cfg(feature = "sync")]
 pub trait PotentiallySync: Sync {}
 #[cfg(feature = "sync")]
-impl<T: Sync> PotentiallySync for T {}
+impl<T: Sync + ?Sized> PotentiallySync for T {}
 /// Sync trait, if feature is enabled, otherwise nothing.
 #[cfg(not(feature = "sync"))]
 pub trait PotentiallySync {}
 #[cfg(not(feature = "sync"))]
-impl<T> PotentiallySync for T {}
+impl<T: ?Sized> PotentiallySync for T {}
 
 /// Activated Send / Sync traits, if enabled.
 #[diagnostic::on_unimplemented(
 	message = "Make sure your type implements Send/Sync according to the activated crate features"
 )]
 pub trait SendSync: PotentiallySend + PotentiallySync {}
-impl<T: PotentiallySend + PotentiallySync> SendSync for T {}
+impl<T: PotentiallySend + PotentiallySync + ?Sized> SendSync for T {}
 
 /// Any + Debug traits with send/sync.
 #[diagnostic::on_unimplemented(
 	message = "Make sure your type implements Debug and Send/Sync according to the activated crate features"
 )]
-pub trait AnyDebugSendSync: Any + Debug + SendSync {}
+pub trait AnyDebugSendSync: Any + Debug + SendSync {
+	/// Name of the concrete type, for diagnostic purposes only.
+	fn type_name(&self) -> &'static str {
+		::core::any::type_name::<Self>()
+	}
+}
 impl<T: Any + Debug + SendSync> AnyDebugSendSync for T {}
 
+/// Any + Debug traits with send/sync for cloneable types, allowing to clone type-erased
+/// attachments.
+pub trait CloneableAny: AnyDebugSendSync {
+	/// Clone the value into a new type-erased box.
+	fn clone_box(&self) -> Box<dyn AnyDebugSendSync>;
+}
+impl<T: AnyDebugSendSync + Clone> CloneableAny for T {
+	fn clone_box(&self) -> Box<dyn AnyDebugSendSync> {
+		Box::new(self.clone())
+	}
+}
+
 /// Error trait with send/sync.
-pub trait ErrorSendSync: Error + SendSync {}
+pub trait ErrorSendSync: Error + SendSync {
+	/// Name of the concrete type, for diagnostic purposes only.
+	fn type_name(&self) -> &'static str {
+		::core::any::type_name::<Self>()
+	}
+}
 impl<T: Error + SendSync> ErrorSendSync for T {}
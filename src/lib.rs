@@ -95,9 +95,16 @@
 //! Failed compiling code (at examples/tool-cli.rs:33:23); Preprocessor failed (at examples/tool-cli.rs:22:25); Binary gcc not found (at examples/tool-cli.rs:17:9)
 //! ```
 //!
+//! The single-line format respects width, fill and alignment, e.g. `{err:<#40}`, to line up errors
+//! in tabular output.
+//!
 //! The error can be formatted using Rust's default debug structure with alternate debug mode
 //! (`{err:#?}`).
 //!
+//! Formatting options, like the style of rendered locations, can be adjusted via
+//! [`NeuErr::as_report`], or applied as reusable [`FormatOptions`] via [`NeuErr::display_with`].
+//! The tree can also be drawn with Unicode box drawing characters via [`TreeStyle::Unicode`].
+//!
 //! ## Comparisons
 //!
 //! ### Anyhow / Eyre
@@ -114,7 +121,8 @@
 //! - `NeuErr` captures source location automatically, which `thiserror` does not and `snafu` does
 //!   only when you add the location field to every error variant.
 //! - `NeuErr` prints the full (source) error chain already.
-//! - `NeuErr` does not have procedural macros.
+//! - `NeuErr` does not have procedural macros by default, only the optional `derive` feature adds
+//!   `#[derive(Attachment)]`.
 //!
 //! ## Feature Flags
 //!
@@ -122,6 +130,8 @@
 //! `default-features=false`.
 //!
 //! **std** (default): Enables use of `std`. Provides interaction with `ExitCode` termination.
+//! Allows hiding all rendered locations, e.g. in production logs, by setting the environment
+//! variable `NEUER_ERROR_HIDE_LOCATIONS=1`.
 //!
 //! **send** (default): Requires all contained types to be `Send`, so that [`NeuErr`] is also
 //! `Send`.
@@ -132,24 +142,141 @@
 //! **colors**: Activates colored error formatting via `yansi` (added dependency). When std it
 //! enabled, it also enables `yansi`'s automatic detection whether to use or not use colors. See
 //! `yansi`'s documentation on details.
+//!
+//! **log**: Enables helpers that log errors via the `log` crate (added dependency), including
+//! [`NeuErr::log`] to log errors with structured key-values.
+//!
+//! **tracing**: Enables helpers that log errors via the `tracing` crate (added dependency).
+//!
+//! **trace-creation** -> tracing: Emits a `tracing` debug event with target `neuer_error`,
+//! including message, location and source error, whenever an error is created via [`NeuErr::new`]
+//! or [`NeuErr::new_with_source`]. Useful during debugging to see where errors originate, filter
+//! via the subscriber at runtime, e.g. `neuer_error=debug`.
+//!
+//! **span-fields** -> std, tracing: Captures the fields of the current `tracing` spans as
+//! [`SpanFields`] attachment at error creation (added dependency `tracing-error`). Requires the
+//! subscriber to record span fields via `tracing_error::ErrorLayer`.
+//!
+//! **serde_json**: Enables attaching and rendering JSON values via [`JsonContext`] (added
+//! dependency).
+//!
+//! **serde**: Enables serializing errors via `serde` (added dependency), e.g. for structured
+//! logging as JSON. Attachments added via [`NeuErr::attach_serialize`] are serialized by value.
+//!
+//! **sentry** -> std: Enables capturing errors as `sentry` events (added dependency).
+//!
+//! **backtrace** -> std: Captures a backtrace at error creation, if enabled via the
+//! `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables. It is printed at the end of the
+//! multi-line format. Locations are still captured as usual.
+//!
+//! **timestamp** -> std: Attaches the creation time as [`CreatedAt`] to errors created via
+//! [`NeuErr::new`], [`NeuErr::new_with_source`] or converted from source errors. It can be shown at
+//! the start of the rendered error via [`Report::with_timestamp`].
+//!
+//! **thread-info** -> std: Attaches the name and ID of the creating thread as [`ThreadInfo`] to
+//! errors created via [`NeuErr::new`], [`NeuErr::new_with_source`] or converted from source
+//! errors. Retrieve it via [`NeuErr::thread`], e.g. to correlate errors with worker logs.
+//!
+//! **defmt**: Implements `defmt::Format` for [`NeuErr`] (added dependency), so errors can be
+//! logged on embedded targets, e.g. over RTT. Messages and locations are written directly without
+//! `core::fmt`, only source errors are formatted via their `Display` implementation.
+//!
+//! **opentelemetry** -> std: Enables recording errors on OpenTelemetry spans (added dependency).
+//!
+//! **smallvec**: Stores the first two context infos inline via `smallvec` (added dependency), so
+//! typical errors with a single message allocate only once. Increases the size of [`NeuErrImpl`],
+//! which by-value APIs like [`NeuErrImpl::into_attachment`] move around, so prefer the boxed
+//! [`NeuErr`] there.
+//!
+//! **derive**: Enables `#[derive(Attachment)]` via the `neuer-error-derive` proc-macro crate
+//! (added dependency), implementing [`Attachment`] to register a name for serialization and
+//! logging, optionally set via `#[attachment(name = "...")]`. Serializing by value and cloning are
+//! opted into via `#[attachment(serialize, clone)]`. Note that this pulls in `syn` and `quote`,
+//! while the default build stays free of proc-macros.
+//!
+//! **test-utils**: Enables utilities for testing errors, like [`NeuErr::diff`].
+//!
+//! **anyhow**: Enables conversions from `anyhow` errors via [`NeuErr::from_anyhow`] and into them
+//! via `From` (with `send` and `sync`, added dependency). The conversions are lossy: Backtraces
+//! are dropped and converted errors cannot be downcast to their original types.
+//!
+//! **miette** -> std: Implements `miette::Diagnostic` for [`NeuErrImpl`] (added dependency), so
+//! errors can be rendered via `miette`'s reporters. [`ErrorCode`], [`Help`] and [`Severity`]
+//! attachments are mapped to their `miette` counterparts.
+//!
+//! **axum** -> std: Provides [`NeuErrResponse`] implementing `axum`'s `IntoResponse` with the
+//! attached `http::StatusCode` (added dependencies `axum-core` and `http`).
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(clippy::std_instead_of_core, clippy::std_instead_of_alloc, clippy::alloc_instead_of_core)]
 
 extern crate alloc;
 
+#[cfg(feature = "anyhow")]
+mod anyhow;
+mod attachments;
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(feature = "defmt")]
+mod defmt;
 mod error;
 mod features;
+#[cfg(feature = "log")]
+mod log;
 mod macros;
+#[cfg(feature = "miette")]
+mod miette;
+#[cfg(feature = "opentelemetry")]
+mod opentelemetry;
+mod report;
 mod results;
+#[cfg(feature = "sentry")]
+mod sentry;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "test-utils")]
+mod testing;
+#[cfg(feature = "span-fields")]
+mod tracing;
+
+#[cfg(all(test, feature = "derive"))]
+extern crate self as neuer_error;
+
+#[doc(hidden)]
+pub use ::alloc::{borrow::Cow as __Cow, format as __format};
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use ::erased_serde::Serialize as __ErasedSerialize;
+#[cfg(feature = "derive")]
+pub use ::neuer_error_derive::Attachment;
 
+#[cfg(feature = "timestamp")]
+pub use self::attachments::CreatedAt;
+#[cfg(feature = "serde_json")]
+pub use self::attachments::JsonContext;
+#[cfg(feature = "std")]
+pub use self::attachments::Panicked;
+#[cfg(feature = "thread-info")]
+pub use self::attachments::ThreadInfo;
+#[cfg(feature = "axum")]
+pub use self::axum::NeuErrResponse;
+#[cfg(feature = "span-fields")]
+pub use self::tracing::SpanFields;
 pub use self::{
-	error::{NeuErr, NeuErrImpl},
-	results::{ConvertOption, ConvertResult, CtxResultExt, ResultExt},
+	attachments::{
+		Attachment, AttachmentSet, ErrorCode, Fields, Help, IntoReturnCode, Keyed, Note,
+		ReturnCode, Sensitive, Severity,
+	},
+	error::{Msg, MultipleErrors, NeuErr, NeuErrImpl, Tracked},
+	report::{FormatOptions, LocationStyle, Report, TreeStyle},
+	results::{ConvertOption, ConvertResult, CtxResultExt, ResultExt, partition_results},
 };
 
 pub mod traits {
 	//! All traits that need to be in scope for	comfortable usage.
-	pub use crate::{ConvertOption as _, ConvertResult as _, CtxResultExt as _, ResultExt as _};
+	pub use crate::{
+		ConvertOption as _, ConvertResult as _, CtxResultExt as _, IntoReturnCode as _,
+		ResultExt as _,
+	};
 }
 
 /// `Result` type alias using the crate's [`NeuErr`] type.
@@ -0,0 +1,119 @@
+//! Integration with `log` for logging errors with structured key-values.
+
+use ::alloc::vec::Vec;
+use ::core::{any::Any, fmt::Debug, panic::Location};
+use ::log::{
+	Level, Metadata, Record,
+	kv::{self, Key, Source, Value, VisitSource},
+};
+
+use crate::{
+	Fields, NeuErr,
+	error::{DisplayedAttachment, Info},
+	features::AnyDebugSendSync,
+	report::Report,
+};
+
+impl NeuErr {
+	/// Log the error via the `log` crate on the given level, with target `neuer_error` and the
+	/// caller's location.
+	///
+	/// - The message is the newest human context message, or the single-line format if there is no
+	///   human context.
+	/// - The key-value `error` holds the single-line format of the whole error.
+	/// - Structured [`Fields`] are added as key-values, other attachments keyed by their type name
+	///   with their `Display` string if attached via [`attach_display`](Self::attach_display),
+	///   otherwise their `Debug` string. For multiple attachments of the same type, only the newest
+	///   is added.
+	#[track_caller]
+	pub fn log(&self, level: Level) {
+		const TARGET: &str = "neuer_error";
+
+		let logger = ::log::logger();
+		if level > ::log::max_level()
+			|| !logger.enabled(&Metadata::builder().level(level).target(TARGET).build())
+		{
+			return;
+		}
+
+		let report = self.as_report().compact();
+		let key_values = KeyValues::new(self, report);
+		let location = Location::caller();
+		let mut record = Record::builder();
+		record
+			.level(level)
+			.target(TARGET)
+			.file(Some(location.file()))
+			.line(Some(location.line()))
+			.key_values(&key_values);
+		match self.0.contexts().next() {
+			Some(context) => logger.log(&record.args(format_args!("{}", context.message)).build()),
+			None => logger.log(&record.args(format_args!("{report}")).build()),
+		}
+	}
+}
+
+/// Value of a logged key-value pair.
+enum LogValue<'a> {
+	/// String value, e.g. of a field.
+	Str(&'a str),
+	/// Attachment that is displayed.
+	Display(DisplayedAttachment<'a>),
+	/// Attachment that is debug formatted.
+	Debug(&'a dyn AnyDebugSendSync),
+}
+
+/// Key-values of a logged error, collected upfront to borrow them for the record.
+struct KeyValues<'a> {
+	/// Single-line format of the error.
+	report: Report<'a>,
+	/// Fields and attachments.
+	pairs: Vec<(&'a str, LogValue<'a>)>,
+}
+
+impl<'a> KeyValues<'a> {
+	/// Collect the key-values of the error.
+	fn new(error: &'a NeuErr, report: Report<'a>) -> Self {
+		let mut pairs =
+			error.fields().map(|(key, value)| (key, LogValue::Str(value))).collect::<Vec<_>>();
+		#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+		let attachments = error
+			.0
+			.infos()
+			.filter_map(|info| match info {
+				Info::Machine(info) => Some(info),
+				Info::Human(_) => None,
+			})
+			.filter(|info| !(info.attachment.as_ref() as &(dyn Any + 'static)).is::<Fields>());
+		for info in attachments {
+			let key = info.type_name();
+			if pairs.iter().any(|(existing, _)| *existing == key) {
+				continue;
+			}
+			let value = match info.displayed() {
+				Some(displayed) => LogValue::Display(displayed),
+				None => LogValue::Debug(info.attachment.as_ref()),
+			};
+			pairs.push((key, value));
+		}
+		Self { report, pairs }
+	}
+}
+
+impl Source for KeyValues<'_> {
+	fn visit<'kvs>(&'kvs self, visitor: &mut dyn VisitSource<'kvs>) -> Result<(), kv::Error> {
+		visitor.visit_pair(Key::from_str("error"), Value::from_dyn_display(&self.report))?;
+		for (key, value) in &self.pairs {
+			let value = match value {
+				LogValue::Str(value) => Value::from(*value),
+				LogValue::Display(displayed) => Value::from_dyn_display(displayed),
+				LogValue::Debug(attachment) => {
+					let attachment: &dyn Debug = *attachment;
+					Value::from_dyn_debug(attachment)
+				}
+			};
+			visitor.visit_pair(Key::from_str(key), value)?;
+		}
+		Ok(())
+	}
+}
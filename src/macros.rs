@@ -54,67 +54,471 @@
 ///
 /// This will create a method `fn user_info(&self) -> String` on `NeuErr`, which collects all
 /// `UserInfo` attachments, unpacks them and collects them into a single `String`.
+///
+/// To return types borrowing from the error, declare a lifetime on the getter, which is tied to
+/// `&self`:
+///
+/// ```rust
+/// # use neuer_error::{NeuErr, provided_attachments};
+/// #[derive(Debug, PartialEq, Clone)]
+/// struct UserInfo(String);
+///
+/// provided_attachments!(
+/// 	user_infos<'a>(multiple: UserInfo) -> impl Iterator<Item = &'a str> + 'a {
+/// 		|iter| iter.map(|UserInfo(s)| s.as_str())
+/// 	};
+/// );
+///
+/// let error = NeuErr::new("test")
+/// 	.attach(UserInfo("alice".to_owned()))
+/// 	.attach(UserInfo("bob".to_owned()));
+/// assert_eq!(error.user_infos().collect::<Vec<_>>(), ["bob", "alice"]);
+/// ```
+///
+/// This will create a method `fn user_infos<'a>(&'a self) -> impl Iterator<Item = &'a str> + 'a`
+/// on `NeuErr`.
+///
+/// Getters for in-place mutation are generated via `single_mut` and `multiple_mut`, which take
+/// `&mut self` and require the error type to provide
+/// [`attachment_mut`](crate::NeuErr::attachment_mut) and
+/// [`attachments_mut`](crate::NeuErr::attachments_mut) respectively:
+///
+/// ```rust
+/// # use neuer_error::{NeuErr, provided_attachments};
+/// #[derive(Debug, Default)]
+/// struct Notes(Vec<String>);
+///
+/// provided_attachments!(
+/// 	notes_mut(single_mut: Notes) -> Option<&mut Notes> { |v| v };
+/// 	notes(single: Notes) -> &[String] { |v| v.map_or(&[], |Notes(notes)| notes.as_slice()) };
+/// );
+///
+/// let mut error = NeuErr::new("test").attach(Notes::default());
+/// if let Some(Notes(notes)) = error.notes_mut() {
+/// 	notes.push("Retried 3 times".to_owned());
+/// }
+/// assert_eq!(error.notes(), ["Retried 3 times"]);
+/// ```
+///
+/// Optionally, the macro also generates helpers to add attachments via `attach` and
+/// `attach_override`, so the provided and consumed information is equally discoverable:
+///
+/// ```rust
+/// # use neuer_error::{NeuErr, provided_attachments};
+/// #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+/// enum Retryable { Yes, No }
+///
+/// provided_attachments!(
+/// 	retryable(single: Retryable) -> bool { |v| matches!(v, Some(Retryable::Yes)) };
+/// 	attach_retryable(attach: Retryable);
+/// 	set_retryable(attach_override: Retryable);
+/// );
+///
+/// let error = NeuErr::new("test").attach_retryable(Retryable::No).set_retryable(Retryable::Yes);
+/// assert!(error.retryable());
+/// assert_eq!(error.attachments::<Retryable>().count(), 1);
+/// ```
+///
+/// This will create methods `fn attach_retryable(self, attachment: Retryable) -> Self` and
+/// `fn set_retryable(self, attachment: Retryable) -> Self` on `NeuErr`.
 #[macro_export]
 macro_rules! provided_attachments {
 	// Declare rule for single attachment.
-	(@declare $getter_name:ident (single: $attachment_type:ty) -> $return_type:ty {
+	(@declare $getter_name:ident $(<$lifetime:lifetime>)? (single: $attachment_type:ty) -> $return_type:ty {
 		// Transformation closure, receiving type Option<&$attachment_type> and returning $return_type.
 		|$bind:ident| $transform:expr
 	}) => {
 		#[doc = concat!("Get attachment `", stringify!($getter_name), "` via type `", stringify!($attachment_type), "` from the error.")]
-		fn $getter_name(&self) -> $return_type;
+		fn $getter_name $(<$lifetime>)? (& $($lifetime)? self) -> $return_type;
 	};
 
 	// Implement rule for single attachment.
-	(@implement $getter_name:ident (single: $attachment_type:ty) -> $return_type:ty {
+	(@implement $getter_name:ident $(<$lifetime:lifetime>)? (single: $attachment_type:ty) -> $return_type:ty {
 		// Transformation closure, receiving type Option<&$attachment_type> and returning $return_type.
 		|$bind:ident| $transform:expr
 	}) => {
-		fn $getter_name(&self) -> $return_type {
+		fn $getter_name $(<$lifetime>)? (& $($lifetime)? self) -> $return_type {
 			let $bind = Self::attachment::<$attachment_type>(self);
 			$transform
 		}
 	};
 
 	// Declare rule for multiple attachment.
-	(@declare $getter_name:ident (multiple: $attachment_type:ty) -> $return_type:ty {
+	(@declare $getter_name:ident $(<$lifetime:lifetime>)? (multiple: $attachment_type:ty) -> $return_type:ty {
 		// Transformation closure, receiving type impl Iterator<Item = &$attachment_type> and returning $return_type.
 		|$bind:ident| $transform:expr
 	}) => {
 		#[doc = concat!("Get attachment `", stringify!($getter_name), "` via type `", stringify!($attachment_type), "` from the error.")]
-		fn $getter_name(&self) -> $return_type;
+		fn $getter_name $(<$lifetime>)? (& $($lifetime)? self) -> $return_type;
 	};
 
 	// Implement rule for multiple attachment.
-	(@implement $getter_name:ident (multiple: $attachment_type:ty) -> $return_type:ty {
+	(@implement $getter_name:ident $(<$lifetime:lifetime>)? (multiple: $attachment_type:ty) -> $return_type:ty {
 		// Transformation closure, receiving type impl Iterator<Item = &$attachment_type> and returning $return_type.
 		|$bind:ident| $transform:expr
 	}) => {
-		fn $getter_name(&self) -> $return_type {
+		fn $getter_name $(<$lifetime>)? (& $($lifetime)? self) -> $return_type {
 			let $bind = Self::attachments::<$attachment_type>(self);
 			$transform
 		}
 	};
 
+	// Declare rule for single mutable attachment.
+	(@declare $getter_name:ident $(<$lifetime:lifetime>)? (single_mut: $attachment_type:ty) -> $return_type:ty {
+		// Transformation closure, receiving type Option<&mut $attachment_type> and returning $return_type.
+		|$bind:ident| $transform:expr
+	}) => {
+		#[doc = concat!("Get attachment `", stringify!($getter_name), "` via type `", stringify!($attachment_type), "` from the error for mutation.")]
+		fn $getter_name $(<$lifetime>)? (& $($lifetime)? mut self) -> $return_type;
+	};
+
+	// Implement rule for single mutable attachment.
+	(@implement $getter_name:ident $(<$lifetime:lifetime>)? (single_mut: $attachment_type:ty) -> $return_type:ty {
+		// Transformation closure, receiving type Option<&mut $attachment_type> and returning $return_type.
+		|$bind:ident| $transform:expr
+	}) => {
+		fn $getter_name $(<$lifetime>)? (& $($lifetime)? mut self) -> $return_type {
+			let $bind = Self::attachment_mut::<$attachment_type>(self);
+			$transform
+		}
+	};
+
+	// Declare rule for multiple mutable attachments.
+	(@declare $getter_name:ident $(<$lifetime:lifetime>)? (multiple_mut: $attachment_type:ty) -> $return_type:ty {
+		// Transformation closure, receiving type impl Iterator<Item = &mut $attachment_type> and returning $return_type.
+		|$bind:ident| $transform:expr
+	}) => {
+		#[doc = concat!("Get attachment `", stringify!($getter_name), "` via type `", stringify!($attachment_type), "` from the error for mutation.")]
+		fn $getter_name $(<$lifetime>)? (& $($lifetime)? mut self) -> $return_type;
+	};
+
+	// Implement rule for multiple mutable attachments.
+	(@implement $getter_name:ident $(<$lifetime:lifetime>)? (multiple_mut: $attachment_type:ty) -> $return_type:ty {
+		// Transformation closure, receiving type impl Iterator<Item = &mut $attachment_type> and returning $return_type.
+		|$bind:ident| $transform:expr
+	}) => {
+		fn $getter_name $(<$lifetime>)? (& $($lifetime)? mut self) -> $return_type {
+			let $bind = Self::attachments_mut::<$attachment_type>(self);
+			$transform
+		}
+	};
+
+	// Declare rule for attaching.
+	(@declare $method_name:ident (attach: $attachment_type:ty)) => {
+		#[doc = concat!("Attach `", stringify!($attachment_type), "` to the error.")]
+		fn $method_name(self, attachment: $attachment_type) -> Self;
+	};
+
+	// Implement rule for attaching.
+	(@implement $method_name:ident (attach: $attachment_type:ty)) => {
+		fn $method_name(self, attachment: $attachment_type) -> Self {
+			Self::attach(self, attachment)
+		}
+	};
+
+	// Declare rule for attaching with override.
+	(@declare $method_name:ident (attach_override: $attachment_type:ty)) => {
+		#[doc = concat!("Set `", stringify!($attachment_type), "` in the error, overriding existing attachments of the type.")]
+		fn $method_name(self, attachment: $attachment_type) -> Self;
+	};
+
+	// Implement rule for attaching with override.
+	(@implement $method_name:ident (attach_override: $attachment_type:ty)) => {
+		fn $method_name(self, attachment: $attachment_type) -> Self {
+			Self::attach_override(self, attachment)
+		}
+	};
+
 	// Main matcher, splitting into attachment list.
 	($(
-		$getter_name:ident ($multiplicity_matcher:ident : $attachment_type:ty) -> $return_type:ty { |$bind:ident| $transform:expr }
+		$method_name:ident $(<$lifetime:lifetime>)? ($multiplicity_matcher:ident : $attachment_type:ty) $(-> $return_type:ty { |$bind:ident| $transform:expr })?
 	);* $(;)?) => {
 		#[doc = "Helper trait that is implemented for [`NeuErr`], which allows to comfortably retrieve typed context information."]
 		pub trait NeuErrAttachments {
 			$(
-				$crate::provided_attachments!(@declare $getter_name($multiplicity_matcher: $attachment_type) -> $return_type {
+				$crate::provided_attachments!(@declare $method_name $(<$lifetime>)? ($multiplicity_matcher: $attachment_type) $(-> $return_type {
 					|$bind| $transform
-				});
+				})?);
 			)*
 		}
 
 		impl NeuErrAttachments for $crate::NeuErr {
 			$(
-				$crate::provided_attachments!(@implement $getter_name($multiplicity_matcher: $attachment_type) -> $return_type {
+				$crate::provided_attachments!(@implement $method_name $(<$lifetime>)? ($multiplicity_matcher: $attachment_type) $(-> $return_type {
 					|$bind| $transform
-				});
+				})?);
 			)*
 		}
 	};
 }
+
+/// Create [`Fields`](crate::Fields) of structured diagnostic key-values, similar to `tracing`'s
+/// field syntax. The values can be of any type implementing `Display` and are captured as strings.
+///
+/// ## Usage
+///
+/// ```rust
+/// # use neuer_error::{NeuErr, fields};
+/// let user_id = 5;
+/// let error = NeuErr::new("Login failed").with_fields(fields! { user_id, action = "login" });
+/// assert_eq!(error.fields().collect::<Vec<_>>(), [("user_id", "5"), ("action", "login")]);
+/// ```
+#[macro_export]
+macro_rules! fields {
+	(@field $fields:expr, $key:ident = $value:expr) => {
+		$fields.with(::core::stringify!($key), $value)
+	};
+	(@field $fields:expr, $key:ident) => {
+		$fields.with(::core::stringify!($key), $key)
+	};
+
+	($($key:ident $(= $value:expr)?),* $(,)?) => {{
+		let fields = $crate::Fields::new();
+		$(
+			let fields = $crate::fields!(@field fields, $key $(= $value)?);
+		)*
+		fields
+	}};
+}
+
+/// Create a [`NeuErr`](crate::NeuErr) with formatted message, source error and attachments in one
+/// expression. The location is captured at the macro invocation.
+///
+/// The message is a format string with optional arguments. It can be followed by a source error
+/// via `; source = <expr>` and a comma-separated list of attachments via `; attach: <exprs>`.
+///
+/// ## Usage
+///
+/// ```rust
+/// # use neuer_error::{NeuErr, neuer};
+/// #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+/// enum Retryable { Yes, No }
+///
+/// let path = "config.toml";
+/// let source = "wahr".parse::<bool>().unwrap_err();
+/// let error = neuer!("Failed to load {path}"; source = source; attach: Retryable::No, 404_u16);
+/// assert_eq!(error.attachment::<Retryable>(), Some(&Retryable::No));
+/// assert_eq!(error.attachment::<u16>(), Some(&404));
+/// assert!(error.source().is_some());
+///
+/// let error: NeuErr = neuer!("Invalid value {}", 5);
+/// ```
+#[macro_export]
+macro_rules! neuer {
+	(@new $message:ident) => {
+		$crate::NeuErr::new($message)
+	};
+	(@new $message:ident, $source:expr) => {
+		$crate::NeuErr::new_with_source($message, $source)
+	};
+
+	(
+		$message:literal $(, $arg:expr)* $(,)?
+		$(; source = $source:expr)?
+		$(; attach: $($attachment:expr),+ $(,)?)?
+		$(;)?
+	) => {{
+		let message = $crate::__format!($message $(, $arg)*);
+		let error = $crate::neuer!(@new message $(, $source)?);
+		$($(
+			let error = error.attach($attachment);
+		)+)?
+		error
+	}};
+}
+
+/// Create a [`NeuErr`](crate::NeuErr) from a formatted message without returning it. The location
+/// is captured at the macro invocation.
+///
+/// Alias of [`neuer!`](crate::neuer), mirroring [`bail!`](crate::bail) without the `return`.
+///
+/// ## Usage
+///
+/// ```rust
+/// # use neuer_error::{NeuErr, format_err};
+/// let mut errors: Vec<NeuErr> = Vec::new();
+/// let id = 5;
+/// errors.push(format_err!("User {id} already exists"));
+/// ```
+#[macro_export]
+macro_rules! format_err {
+	($($arg:tt)+) => {
+		$crate::neuer!($($arg)+)
+	};
+}
+
+/// Return early with a [`NeuErr`](crate::NeuErr) created from a formatted message. The location
+/// is captured at the macro invocation.
+///
+/// Accepts the same arguments as [`neuer!`](crate::neuer), so a source error and attachments can
+/// be added as well.
+///
+/// ## Usage
+///
+/// ```rust
+/// # use neuer_error::{Result, bail};
+/// fn find_user(id: u32) -> Result<()> {
+/// 	bail!("User {id} not found");
+/// }
+///
+/// assert!(find_user(5).is_err());
+/// ```
+#[macro_export]
+macro_rules! bail {
+	($($arg:tt)+) => {
+		return ::core::result::Result::Err($crate::neuer!($($arg)+))
+	};
+}
+
+/// Return early with a [`NeuErr`](crate::NeuErr) created from a formatted message, if the
+/// condition is not met. The location is captured at the macro invocation.
+///
+/// Besides the arguments of [`neuer!`](crate::neuer), a single attachment can be given via
+/// `; attach = <expr>`.
+///
+/// ## Usage
+///
+/// ```rust
+/// # use neuer_error::{Result, ensure};
+/// fn check_age(age: u8) -> Result<()> {
+/// 	ensure!(age >= 18, "Age {age} is too young"; attach = age);
+/// 	Ok(())
+/// }
+///
+/// assert!(check_age(18).is_ok());
+/// assert_eq!(check_age(5).unwrap_err().attachment::<u8>(), Some(&5));
+/// ```
+#[macro_export]
+macro_rules! ensure {
+	($cond:expr, $message:literal $(, $arg:expr)* $(,)?; attach = $attachment:expr $(;)?) => {
+		if !$cond {
+			$crate::bail!($message $(, $arg)*; attach: $attachment);
+		}
+	};
+	($cond:expr, $($arg:tt)+) => {
+		if !$cond {
+			$crate::bail!($($arg)+);
+		}
+	};
+}
+
+/// Declare an error kind enum, that is attached to errors and retrieved via a `kind()` getter, to
+/// let library users match on the kind of errors.
+///
+/// The enum derives `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq` and `Hash` and is
+/// `#[non_exhaustive]`, unless declared as `exhaustive enum`. The macro also invokes
+/// [`provided_attachments!`](crate::provided_attachments), generating the `NeuErrAttachments`
+/// trait with the getter `kind()` and the setter `attach_kind()`, which overrides existing kinds.
+/// Further entries for [`provided_attachments!`](crate::provided_attachments) can follow the enum.
+///
+/// Each kind provides helpers to create errors of this kind:
+///
+/// - `error(message)` creates a new error with the kind attached.
+/// - `ensure(condition, message)` returns such an error if the condition is not met, like
+///   [`ensure!`](crate::ensure).
+///
+/// ## Usage
+///
+/// ```rust
+/// # use neuer_error::{Result, error_kinds};
+/// #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+/// pub enum Retryable {
+/// 	Yes,
+/// 	No,
+/// }
+///
+/// error_kinds! {
+/// 	/// Kinds of errors that are interesting to match on for library users.
+/// 	pub enum ErrorKind {
+/// 		/// The requested resource does not exist.
+/// 		NotFound,
+/// 		InvalidInput,
+/// 	}
+///
+/// 	retryable(single: Retryable) -> bool { |v| matches!(v, Some(Retryable::Yes)) };
+/// }
+///
+/// fn find_user(id: u32) -> Result<()> {
+/// 	ErrorKind::InvalidInput.ensure(id > 0, "User ID must be positive")?;
+/// 	Err(ErrorKind::NotFound.error("User not found").attach(Retryable::No))
+/// }
+///
+/// let error = find_user(0).unwrap_err();
+/// assert_eq!(error.kind(), Some(ErrorKind::InvalidInput));
+/// let error = find_user(5).unwrap_err();
+/// assert_eq!(error.kind(), Some(ErrorKind::NotFound));
+/// assert!(!error.retryable());
+/// ```
+#[macro_export]
+macro_rules! error_kinds {
+	// Implement the helpers to create errors of the kind.
+	(@helpers $name:ident) => {
+		impl $name {
+			/// Create a new error of this kind with the given message.
+			#[track_caller]
+			#[must_use]
+			pub fn error<C>(self, message: C) -> $crate::NeuErr
+			where
+				C: ::core::convert::Into<$crate::__Cow<'static, str>>,
+			{
+				$crate::NeuErr::new(message).attach_override(self)
+			}
+
+			/// Return an error of this kind with the given message, if the condition is not met.
+			#[track_caller]
+			pub fn ensure<C>(self, condition: bool, message: C) -> $crate::Result<()>
+			where
+				C: ::core::convert::Into<$crate::__Cow<'static, str>>,
+			{
+				if condition { $crate::Ok(()) } else { ::core::result::Result::Err(self.error(message)) }
+			}
+		}
+	};
+
+	// Exhaustive enum.
+	(
+		$(#[$meta:meta])*
+		$vis:vis exhaustive enum $name:ident {
+			$($(#[$variant_meta:meta])* $variant:ident),* $(,)?
+		}
+		$($attachments:tt)*
+	) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+		$vis enum $name {
+			$($(#[$variant_meta])* $variant),*
+		}
+
+		$crate::error_kinds!(@helpers $name);
+
+		$crate::provided_attachments!(
+			kind(single: $name) -> ::core::option::Option<$name> { |kind| kind.copied() };
+			attach_kind(attach_override: $name);
+			$($attachments)*
+		);
+	};
+
+	// Non-exhaustive enum.
+	(
+		$(#[$meta:meta])*
+		$vis:vis enum $name:ident {
+			$($(#[$variant_meta:meta])* $variant:ident),* $(,)?
+		}
+		$($attachments:tt)*
+	) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+		#[non_exhaustive]
+		$vis enum $name {
+			$($(#[$variant_meta])* $variant),*
+		}
+
+		$crate::error_kinds!(@helpers $name);
+
+		$crate::provided_attachments!(
+			kind(single: $name) -> ::core::option::Option<$name> { |kind| kind.copied() };
+			attach_kind(attach_override: $name);
+			$($attachments)*
+		);
+	};
+}
@@ -0,0 +1,50 @@
+//! Integration with `miette` for rendering errors via its reporters.
+
+use ::alloc::{boxed::Box, vec::Vec};
+use ::core::fmt::Display;
+use ::miette::Diagnostic;
+
+use crate::{ErrorCode, Help, NeuErrImpl, Severity};
+
+impl Diagnostic for NeuErrImpl {
+	/// The newest [`ErrorCode`] attachment.
+	fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+		let ErrorCode(code) = self.attachment::<ErrorCode>()?;
+		Some(Box::new(code))
+	}
+
+	/// The [`Severity`] attachment, where [`Severity::Fatal`] is mapped to an error.
+	fn severity(&self) -> Option<::miette::Severity> {
+		self.attachment::<Severity>().map(|severity| match severity {
+			Severity::Warning => ::miette::Severity::Warning,
+			Severity::Error | Severity::Fatal => ::miette::Severity::Error,
+		})
+	}
+
+	/// All [`Help`] attachments, one per line in the order they were attached.
+	fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+		let mut helps =
+			self.attachments::<Help>().map(|Help(help)| help.as_ref()).collect::<Vec<_>>();
+		if helps.is_empty() {
+			return None;
+		}
+		helps.reverse();
+		Some(Box::new(helps.join("\n")))
+	}
+
+	/// The immediate source error, if it is a [`NeuErrImpl`] itself.
+	fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+		let source = self.chain().next()?.downcast_ref::<Self>()?;
+		Some(source)
+	}
+}
+
+#[cfg(all(feature = "send", feature = "sync"))]
+impl From<crate::NeuErr> for ::miette::Report {
+	/// Convert the error into a `miette` report, e.g. to return it from `main` with `miette`'s
+	/// fancy reporter.
+	#[inline]
+	fn from(error: crate::NeuErr) -> Self {
+		Self::new(error.into_error())
+	}
+}
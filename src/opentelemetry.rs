@@ -0,0 +1,57 @@
+//! Integration with `opentelemetry` for recording errors on spans.
+
+use ::core::any::Any;
+use ::opentelemetry::{
+	KeyValue,
+	trace::{Span, Status},
+};
+
+use crate::{Fields, NeuErr, error::Info};
+
+impl NeuErr {
+	/// Record the error on an OpenTelemetry span.
+	///
+	/// - The span status is set to error, described by the newest human context message.
+	/// - An `exception` event is added with `exception.type` (type name of the immediate source
+	///   error or `NeuErr`) and `exception.message` (the single-line format).
+	/// - Structured [`Fields`](crate::Fields) are added to the event as key-values, other
+	///   attachments keyed by their type name with their `Debug` string as value. For multiple
+	///   attachments of the same type, only the newest is added.
+	pub fn record_otel<S>(&self, span: &mut S)
+	where
+		S: Span,
+	{
+		if !span.is_recording() {
+			return;
+		}
+
+		let description = self.0.contexts().next().map(|context| context.message.clone());
+		span.set_status(Status::error(description.unwrap_or_default()));
+
+		let ty = self.source().map_or("NeuErr", |source| source.type_name());
+		let mut attributes = vec![
+			KeyValue::new("exception.type", ty),
+			KeyValue::new("exception.message", format!("{self:#}")),
+		];
+		for (key, value) in self.fields() {
+			attributes.push(KeyValue::new(key, value.to_owned()));
+		}
+		#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+		let attachments = self
+			.0
+			.infos()
+			.filter_map(|info| match info {
+				Info::Machine(info) => Some(info),
+				Info::Human(_) => None,
+			})
+			.filter(|info| !(info.attachment.as_ref() as &(dyn Any + 'static)).is::<Fields>());
+		for info in attachments {
+			let key = info.type_name();
+			let attachment = info.attachment.as_ref();
+			if !attributes.iter().any(|attribute| attribute.key.as_str() == key) {
+				attributes.push(KeyValue::new(key, format!("{attachment:?}")));
+			}
+		}
+		span.add_event("exception", attributes);
+	}
+}
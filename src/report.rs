@@ -0,0 +1,1054 @@
+//! Configurable error formatting.
+
+use ::alloc::{
+	borrow::Cow,
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
+use ::core::{
+	any::Any,
+	error::Error,
+	fmt::{Alignment, Display, Formatter, Result as FmtResult, Write},
+	panic::Location,
+};
+#[cfg(feature = "colors")]
+use ::yansi::{Condition, Paint};
+
+use crate::{
+	ErrorCode, Fields, Help, MultipleErrors, NeuErr, NeuErrImpl, Note,
+	error::{DisplayedAttachment, HumanInfo, Info},
+};
+
+/// Style of the tree structure drawn by the multi-line format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TreeStyle {
+	/// Simple ASCII prefixes (`|-` and `|`) with all source errors on the same level.
+	#[default]
+	Ascii,
+	/// Unicode box drawing characters (`├─`, `└─` and `│`), with the indentation increasing per
+	/// source error, e.g. for terminals with proper Unicode support.
+	Unicode,
+}
+
+impl TreeStyle {
+	/// Symbols to draw the tree with.
+	const fn symbols(self) -> &'static Symbols {
+		match self {
+			Self::Ascii => &Symbols {
+				branch: "|- ",
+				last: "|- ",
+				line: "|",
+				indent: "|  ",
+				closes: false,
+				legend: "|- at = source location, caused by: = underlying cause",
+			},
+			Self::Unicode => &Symbols {
+				branch: "├─ ",
+				last: "└─ ",
+				line: "│",
+				indent: "│  ",
+				closes: true,
+				legend: "├─ at = source location, caused by: = underlying cause",
+			},
+		}
+	}
+}
+
+/// Symbols drawing the tree structure of the multi-line format.
+#[derive(Debug)]
+struct Symbols {
+	/// Prefix of an entry.
+	branch: &'static str,
+	/// Prefix of the last entry among its siblings.
+	last: &'static str,
+	/// Vertical line between entries.
+	line: &'static str,
+	/// Indentation below an entry with more entries following.
+	indent: &'static str,
+	/// Whether the line ends at the last entry, so nothing is drawn below it.
+	closes: bool,
+	/// Legend explaining the symbols.
+	legend: &'static str,
+}
+
+/// Style in which source code locations are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LocationStyle {
+	/// Full file path as recorded by the compiler, line and column, e.g. `src/foo.rs:12:5`.
+	#[default]
+	Full,
+	/// File name without directories, line and column, e.g. `foo.rs:12:5`.
+	FileName,
+	/// File name without directories and line, e.g. `foo.rs:12`.
+	FileLine,
+	/// Do not render locations at all, including backtraces.
+	Hidden,
+}
+
+/// Location rendered in a certain style.
+#[derive(Debug, Clone, Copy)]
+struct StyledLocation {
+	/// Location to render.
+	location: &'static Location<'static>,
+	/// Style to render the location in.
+	style: LocationStyle,
+	/// Path prefix to strip from the full file path.
+	strip_prefix: &'static str,
+}
+
+impl Display for StyledLocation {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		let location = self.location;
+		let file_name = location.file().rsplit(['/', '\\']).next().unwrap_or_default();
+		match self.style {
+			LocationStyle::Full => {
+				// Only strip whole path components, e.g. `src` from `src/lib.rs`, not
+				// `srcfoo/lib.rs`.
+				let file = match location.file().strip_prefix(self.strip_prefix) {
+					Some(file)
+						if !self.strip_prefix.is_empty()
+							&& (file.is_empty()
+								|| file.starts_with(['/', '\\'])
+								|| self.strip_prefix.ends_with(['/', '\\'])) =>
+					{
+						file.trim_start_matches(['/', '\\'])
+					}
+					_ => location.file(),
+				};
+				write!(f, "{file}:{}:{}", location.line(), location.column())
+			}
+			LocationStyle::FileName => {
+				write!(f, "{file_name}:{}:{}", location.line(), location.column())
+			}
+			LocationStyle::FileLine => write!(f, "{file_name}:{}", location.line()),
+			LocationStyle::Hidden => Ok(()),
+		}
+	}
+}
+
+/// Reusable set of formatting options, e.g. to keep in the configuration of a logger. Apply them
+/// via [`NeuErr::display_with`] or [`Report::with_options`].
+///
+/// The default options reproduce the error's `Display` implementation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FormatOptions {
+	/// Style of rendered locations. [`LocationStyle::Hidden`] hides them, e.g. to not leak source
+	/// paths into production logs. With `std`, setting the environment variable
+	/// `NEUER_ERROR_HIDE_LOCATIONS=1` hides them for all errors, regardless of this option.
+	pub location_style: LocationStyle,
+	/// Whether to print a legend explaining the formatting symbols in the multi-line format.
+	pub legend: bool,
+	/// Whether to sort displayed attachments by type name instead of newest first.
+	pub sort_attachments: bool,
+	/// Whether to always use the single-line format.
+	pub compact: bool,
+	/// Whether to render colors, if the `colors` feature is enabled.
+	pub colors: bool,
+	/// Message to render for errors without human context and source error.
+	pub unknown_message: &'static str,
+	/// Separator between the parts of the single-line format.
+	pub separator: &'static str,
+	/// Maximum number of source errors to render, the remaining ones are summarized as
+	/// `... (3 more)`. Sources of nested errors count towards the same limit. Unlimited if `None`.
+	pub max_source_depth: Option<usize>,
+	/// Style of the tree structure of the multi-line format.
+	pub tree_style: TreeStyle,
+	/// Path prefix to strip from file paths of locations rendered in the [`LocationStyle::Full`]
+	/// style, e.g. the workspace root. Only whole path components are stripped, paths not starting
+	/// with the prefix are rendered unchanged.
+	pub strip_path_prefix: &'static str,
+	/// Whether to render the error's creation time at the start of the top line, if the
+	/// `timestamp` feature is enabled.
+	pub timestamp: bool,
+	/// Maximum number of human contexts to render, newest first. The remaining ones are summarized
+	/// as `... (3 more contexts)`. Unlimited if `None`.
+	pub max_contexts: Option<usize>,
+	/// Indentation of entries below other entries in the multi-line format, e.g. attachments
+	/// spanning multiple lines or nested source errors. Defaults to the indentation of the
+	/// [`TreeStyle`] if `None`.
+	pub indentation: Option<&'static str>,
+}
+
+impl FormatOptions {
+	/// Default formatting options.
+	pub const DEFAULT: Self = Self {
+		location_style: LocationStyle::Full,
+		legend: false,
+		sort_attachments: false,
+		compact: false,
+		colors: true,
+		unknown_message: "Unknown error",
+		separator: "; ",
+		max_source_depth: None,
+		tree_style: TreeStyle::Ascii,
+		strip_path_prefix: "",
+		timestamp: false,
+		max_contexts: None,
+		indentation: None,
+	};
+}
+
+impl Default for FormatOptions {
+	#[inline]
+	fn default() -> Self {
+		Self::DEFAULT
+	}
+}
+
+/// Formatter for errors with configurable formatting options.
+///
+/// Without changing any options, it formats exactly like the error's `Display` implementation: The
+/// normal format (`"{report}"`) is multi-line, the alternate format (`"{report:#}"`) is a compact
+/// single-line version.
+#[derive(Debug, Clone, Copy)]
+#[must_use]
+pub struct Report<'a> {
+	/// Error to format.
+	error: &'a NeuErrImpl,
+	/// Formatting options.
+	options: FormatOptions,
+	/// Whether more entries follow the error on the same level, e.g. when rendering a nested
+	/// error, so its last entry does not end the line.
+	continued: bool,
+}
+
+impl<'a> Report<'a> {
+	/// Create a new report of the error with default options.
+	#[inline]
+	pub(crate) const fn new(error: &'a NeuErrImpl) -> Self {
+		Self { error, options: FormatOptions::DEFAULT, continued: false }
+	}
+
+	/// Replace all formatting options.
+	#[inline]
+	pub const fn with_options(mut self, options: FormatOptions) -> Self {
+		self.options = options;
+		self
+	}
+
+	/// Set the separator between the parts of the single-line format. Defaults to `"; "`.
+	#[inline]
+	pub const fn separator(mut self, separator: &'static str) -> Self {
+		self.options.separator = separator;
+		self
+	}
+
+	/// Render at most the given number of source errors, summarizing the remaining ones as
+	/// `... (3 more)`, e.g. to not flood logs with deep error chains. Renders all source errors by
+	/// default.
+	#[inline]
+	pub const fn max_source_depth(mut self, depth: usize) -> Self {
+		self.options.max_source_depth = Some(depth);
+		self
+	}
+
+	/// Set the style of the tree structure of the multi-line format. Defaults to
+	/// [`TreeStyle::Ascii`].
+	#[inline]
+	pub const fn tree_style(mut self, style: TreeStyle) -> Self {
+		self.options.tree_style = style;
+		self
+	}
+
+	/// Render at most the given number of human contexts, newest first, summarizing the remaining
+	/// ones as `... (3 more contexts)`. Renders all contexts by default.
+	#[inline]
+	pub const fn max_contexts(mut self, max: usize) -> Self {
+		self.options.max_contexts = Some(max);
+		self
+	}
+
+	/// Strip the given path prefix from file paths of locations rendered in the
+	/// [`LocationStyle::Full`] style, e.g. the crate root to render `src/foo.rs:12:3`. Paths not
+	/// starting with the prefix are rendered unchanged. Strips nothing by default.
+	#[inline]
+	pub const fn strip_path_prefix(mut self, prefix: &'static str) -> Self {
+		self.options.strip_path_prefix = prefix;
+		self
+	}
+
+	/// Set the indentation of entries below other entries in the multi-line format, e.g. `"    "`
+	/// for plain spaces. Defaults to the indentation of the [`TreeStyle`], e.g. `"|  "`.
+	#[inline]
+	pub const fn indentation(mut self, indentation: &'static str) -> Self {
+		self.options.indentation = Some(indentation);
+		self
+	}
+
+	/// Render the error's creation time at the start of the top line, if the `timestamp` feature
+	/// is enabled. Disabled by default.
+	#[inline]
+	pub const fn with_timestamp(mut self) -> Self {
+		self.options.timestamp = true;
+		self
+	}
+
+	/// Set the style in which locations are rendered. Defaults to [`LocationStyle::Full`].
+	#[inline]
+	pub const fn location_style(mut self, style: LocationStyle) -> Self {
+		self.options.location_style = style;
+		self
+	}
+
+	/// Print a one-line legend below the error, explaining the formatting symbols to readers
+	/// unfamiliar with them. Only applies to the multi-line format. Disabled by default.
+	#[inline]
+	pub const fn with_legend(mut self) -> Self {
+		self.options.legend = true;
+		self
+	}
+
+	/// Render displayed attachments ordered alphabetically by type name instead of newest first,
+	/// for deterministic output, e.g. in snapshot tests. Disabled by default.
+	#[inline]
+	pub const fn sort_attachments(mut self) -> Self {
+		self.options.sort_attachments = true;
+		self
+	}
+
+	/// Always use the compact single-line format, regardless of the alternate flag.
+	#[inline]
+	pub const fn compact(mut self) -> Self {
+		self.options.compact = true;
+		self
+	}
+
+	/// Render without colors, regardless of the `colors` feature and global color settings.
+	#[inline]
+	pub const fn without_colors(mut self) -> Self {
+		self.options.colors = false;
+		self
+	}
+
+	/// Set the message rendered for errors without any human context and source error, e.g. for
+	/// localization. Defaults to `Unknown error`.
+	#[inline]
+	pub const fn unknown_message(mut self, message: &'static str) -> Self {
+		self.options.unknown_message = message;
+		self
+	}
+
+	/// Write an error consisting of a single human context only in the multi-line format, without
+	/// the overhead of the general path.
+	fn write_single(&self, f: &mut Formatter<'_>, context: &HumanInfo) -> FmtResult {
+		#[cfg(feature = "colors")]
+		let message = context.message.as_ref().red().whenever(self.colors());
+		#[cfg(not(feature = "colors"))]
+		let message = context.message.as_ref();
+		write!(f, "{message}")?;
+
+		if self.effective_location_style() != LocationStyle::Hidden {
+			let location = self.location(context.location);
+			#[cfg(feature = "colors")]
+			let location = location.rgb(0x90, 0x90, 0x90).whenever(self.colors());
+			write!(f, "\n{}at {location}", self.branch(!self.continued))?;
+		}
+		Ok(())
+	}
+
+	/// Write the source error chain.
+	///
+	/// Without human contexts (`headless`), the immediate source starts the error. `followed`
+	/// tells whether more entries follow the source errors on the top level.
+	fn write_sources(&self, f: &mut Formatter<'_>, headless: bool, followed: bool) -> FmtResult {
+		let symbols = self.symbols();
+		let mut source_location = self
+			.error
+			.source_location()
+			.filter(|_| self.effective_location_style() != LocationStyle::Hidden);
+		// Whether source errors are nested below the previous one instead of following it.
+		let nesting = self.depth(1) > 0;
+		let mut chain = self.error.chain().enumerate().peekable();
+		while let Some((i, err)) = chain.next() {
+			let header = headless && i == 0;
+			// Depth of the source error's entry, its own entries are one level deeper.
+			let depth = self.depth(i.saturating_sub(usize::from(headless)));
+			let indent = self.indent(depth, followed);
+			if self.options.max_source_depth.is_some_and(|max| i >= max) {
+				let remaining = 1 + chain.count();
+				if header {
+					write!(f, "... ({remaining} more)")?;
+				} else if f.alternate() {
+					write!(f, "{}... ({remaining} more)", self.options.separator)?;
+				} else {
+					let branch = self.branch(depth > 0 || !followed);
+					write!(
+						f,
+						"\n{indent}{}\n{indent}{branch}... ({remaining} more)",
+						symbols.line
+					)?;
+				}
+				break;
+			}
+			if let Some(nested) = err.downcast_ref::<NeuErrImpl>() {
+				// Nested errors render their own sources, so the chain ends here.
+				return self.write_nested(
+					f,
+					nested,
+					i + 1,
+					source_location,
+					header,
+					depth,
+					followed,
+				);
+			}
+
+			#[cfg(feature = "colors")]
+			let error = err.red().whenever(self.colors());
+			#[cfg(not(feature = "colors"))]
+			let error = err;
+
+			if header {
+				write!(f, "{error}")?;
+			} else if f.alternate() {
+				write!(f, "{}caused by: {error}", self.options.separator)?;
+			} else {
+				let last = depth > 0 || (!followed && (nesting || chain.peek().is_none()));
+				let branch = self.branch(last);
+				write!(f, "\n{indent}{}\n{indent}{branch}caused by: {error}", symbols.line)?;
+			}
+			let multiple = err.downcast_ref::<MultipleErrors>();
+			// Only the immediate source can have a recorded location.
+			if let Some(location) = source_location.take() {
+				let location = self.location(location);
+				#[cfg(feature = "colors")]
+				let location = location.rgb(0x90, 0x90, 0x90).whenever(self.colors());
+				if f.alternate() {
+					write!(f, " (at {location})")?;
+				} else {
+					let depth = self.depth(i + 1 - usize::from(headless));
+					let last =
+						multiple.is_none() && chain.peek().is_none() && (depth > 0 || !followed);
+					let indent = self.indent(depth, followed);
+					write!(f, "\n{indent}{}at {location}", self.branch(last))?;
+				}
+			}
+
+			if let Some(errors) = multiple {
+				self.write_multiple(f, errors, depth, followed)?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Write a source error that is itself a [`NeuErrImpl`] in its full format, using the same
+	/// options. `levels` is the number of source levels rendered up to and including this one.
+	#[expect(clippy::too_many_arguments, reason = "Internal helper")]
+	fn write_nested(
+		&self,
+		f: &mut Formatter<'_>,
+		nested: &NeuErrImpl,
+		levels: usize,
+		source_location: Option<&'static Location<'static>>,
+		header: bool,
+		depth: usize,
+		followed: bool,
+	) -> FmtResult {
+		let symbols = self.symbols();
+		// The location follows the nested error's entries, as do top-level entries of a header.
+		let continued = source_location.is_some() || (header && followed);
+		let nested = self.nested(nested, levels, continued);
+		if header {
+			if f.alternate() {
+				write!(f, "{nested:#}")?;
+			} else {
+				write!(f, "{nested}")?;
+			}
+		} else if f.alternate() {
+			write!(f, "{}caused by: {nested:#}", self.options.separator)?;
+		} else {
+			let indent = self.indent(depth, followed);
+			let branch = self.branch(depth > 0 || !followed);
+			write!(f, "\n{indent}{}\n{indent}{branch}caused by: ", symbols.line)?;
+			write!(Indented { f, indent: &self.indent(depth + 1, followed) }, "{nested}")?;
+		}
+
+		if let Some(location) = source_location {
+			let location = self.location(location);
+			#[cfg(feature = "colors")]
+			let location = location.rgb(0x90, 0x90, 0x90).whenever(self.colors());
+			if f.alternate() {
+				write!(f, " (at {location})")?;
+			} else {
+				let depth = if header { depth } else { self.depth(depth + 1) };
+				let indent = self.indent(depth, followed);
+				write!(f, "\n{indent}{}at {location}", self.branch(depth > 0 || !followed))?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Write the individual errors of [`MultipleErrors`], numbered and including their source
+	/// chains. They are indented one level deeper than the entry at the given depth.
+	fn write_multiple(
+		&self,
+		f: &mut Formatter<'_>,
+		errors: &MultipleErrors,
+		depth: usize,
+		followed: bool,
+	) -> FmtResult {
+		let symbols = self.symbols();
+		let separator = self.options.separator;
+		let prefix = self.indent(depth + 1, followed);
+		for (i, err) in errors.iter().enumerate() {
+			let number = i + 1;
+			#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+			let nested = (err as &(dyn Error + 'static))
+				.downcast_ref::<NeuErrImpl>()
+				.map(|nested| self.nested(nested, 0, false));
+			if f.alternate() {
+				let Some(nested) = nested else {
+					write!(f, "{separator}error {number}: {err:#}")?;
+					let mut source = err.source();
+					while let Some(cause) = source {
+						write!(f, "{separator}caused by: {cause}")?;
+						source = cause.source();
+					}
+					continue;
+				};
+				write!(f, "{separator}error {number}: {nested:#}")?;
+				continue;
+			}
+
+			let last = number == errors.len();
+			write!(f, "\n{prefix}{}\n{prefix}{}error {number}: ", symbols.line, self.branch(last))?;
+			let indent = format!("{prefix}{}", self.indent(1, !last));
+			let mut indented = Indented { f, indent: &indent };
+			if let Some(nested) = nested {
+				// Nested errors render their own sources.
+				write!(indented, "{nested}")?;
+				continue;
+			}
+			write!(indented, "{err}")?;
+			let mut source = err.source();
+			while let Some(cause) = source {
+				write!(indented, "\n{}\n{}caused by: {cause}", symbols.line, symbols.branch)?;
+				source = cause.source();
+			}
+		}
+		Ok(())
+	}
+
+	/// Write the [`Note`] and [`Help`] hints in a separate section, in the order they were
+	/// attached.
+	fn write_hints(&self, f: &mut Formatter<'_>) -> FmtResult {
+		#[expect(trivial_casts, reason = "Not that trivial as it seems? False positive")]
+		let hints = self.error.infos().rev().filter_map(|info| {
+			let Info::Machine(info) = info else { return None };
+			let attachment = info.attachment.as_ref() as &(dyn Any + 'static);
+			attachment
+				.downcast_ref::<Note>()
+				.map(|Note(note)| ("note", note))
+				.or_else(|| attachment.downcast_ref::<Help>().map(|Help(help)| ("help", help)))
+		});
+
+		let mut first = true;
+		for (kind, hint) in hints {
+			if f.alternate() {
+				write!(f, "{}{kind}: {hint}", self.options.separator)?;
+			} else {
+				if first {
+					writeln!(f)?;
+				}
+				write!(f, "\n= {kind}: ")?;
+				write!(Indented { f, indent: "  " }, "{hint}")?;
+			}
+			first = false;
+		}
+		Ok(())
+	}
+
+	/// Attachments displayed when formatting the error, in the configured order.
+	fn displayed_attachments(&self) -> Vec<DisplayedAttachment<'a>> {
+		let mut displayed = self
+			.error
+			.infos()
+			.filter_map(|info| match info {
+				Info::Machine(info) => info.displayed(),
+				Info::Human(_) => None,
+			})
+			.collect::<Vec<_>>();
+		if self.options.sort_attachments {
+			displayed.sort_by_key(DisplayedAttachment::type_name);
+		}
+		displayed
+	}
+
+	/// Write the displayed attachments. `followed` tells whether more entries follow them on the
+	/// top level.
+	fn write_attachments(
+		&self,
+		f: &mut Formatter<'_>,
+		displayed: &[DisplayedAttachment<'_>],
+		followed: bool,
+	) -> FmtResult {
+		let symbols = self.symbols();
+		for (i, attachment) in displayed.iter().enumerate() {
+			if f.alternate() {
+				write!(f, "{}{attachment}", self.options.separator)?;
+			} else {
+				let last = !followed && i + 1 == displayed.len();
+				write!(f, "\n{}\n{}", symbols.line, self.branch(last))?;
+				write!(Indented { f, indent: &self.indent(1, !last) }, "{attachment:#}")?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Report of a nested error with the same options, to render it as part of this error. The
+	/// maximum source depth is reduced by the given number of already rendered source levels.
+	#[inline]
+	fn nested<'b>(&self, error: &'b NeuErrImpl, levels: usize, continued: bool) -> Report<'b> {
+		let max_source_depth = self.options.max_source_depth.map(|max| max.saturating_sub(levels));
+		Report {
+			error,
+			options: FormatOptions {
+				legend: false,
+				compact: false,
+				max_source_depth,
+				..self.options
+			},
+			continued,
+		}
+	}
+
+	/// Condition to render colors with, disabling them if configured via the options.
+	#[cfg(feature = "colors")]
+	#[inline]
+	const fn colors(&self) -> Condition {
+		if self.options.colors { Condition::ALWAYS } else { Condition::NEVER }
+	}
+
+	/// Symbols to draw the tree structure of the multi-line format with.
+	#[inline]
+	const fn symbols(&self) -> &'static Symbols {
+		self.options.tree_style.symbols()
+	}
+
+	/// Depth of entries at the given level of the source chain. It only increases with the
+	/// [`TreeStyle::Unicode`] style, the ASCII style renders all source errors on the same level.
+	#[inline]
+	const fn depth(&self, level: usize) -> usize {
+		match self.options.tree_style {
+			TreeStyle::Ascii => 0,
+			TreeStyle::Unicode => level,
+		}
+	}
+
+	/// Indentation of entries below other entries, either configured or of the tree style.
+	#[inline]
+	const fn indent_unit(&self) -> &'static str {
+		match self.options.indentation {
+			Some(indentation) => indentation,
+			None => self.symbols().indent,
+		}
+	}
+
+	/// Prefix of an entry, depending on whether it is the last among its siblings.
+	#[inline]
+	const fn branch(&self, last: bool) -> &'static str {
+		let symbols = self.symbols();
+		if last { symbols.last } else { symbols.branch }
+	}
+
+	/// Indentation of entries at the given depth. Below the first level, each level is indented by
+	/// spaces of the same width as the indentation. Below the last top-level entry (not `open`),
+	/// styles ending the line there indent by spaces only.
+	fn indent(&self, depth: usize, open: bool) -> Cow<'static, str> {
+		let indentation = self.indent_unit();
+		let width = indentation.chars().count();
+		if depth > 0 && !open && self.symbols().closes {
+			return Cow::Owned(" ".repeat(width * depth));
+		}
+		match depth {
+			0 => Cow::Borrowed(""),
+			1 => Cow::Borrowed(indentation),
+			_ => {
+				let mut indent = String::from(indentation);
+				indent.extend(::core::iter::repeat_n(' ', width * (depth - 1)));
+				Cow::Owned(indent)
+			}
+		}
+	}
+
+	/// Style of rendered locations, hidden regardless of the options if the
+	/// `NEUER_ERROR_HIDE_LOCATIONS` environment variable is set to `1` (with `std`).
+	#[inline]
+	#[cfg_attr(
+		not(feature = "std"),
+		expect(clippy::missing_const_for_fn, reason = "Not const with std")
+	)]
+	fn effective_location_style(&self) -> LocationStyle {
+		#[cfg(feature = "std")]
+		if locations_hidden_by_env() {
+			return LocationStyle::Hidden;
+		}
+		self.options.location_style
+	}
+
+	/// Render the location in the configured style.
+	#[inline]
+	fn location(&self, location: &'static Location<'static>) -> StyledLocation {
+		StyledLocation {
+			location,
+			style: self.effective_location_style(),
+			strip_prefix: self.options.strip_path_prefix,
+		}
+	}
+}
+
+impl Display for Report<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		let report = *self;
+		if self.options.compact && !f.alternate() {
+			return match f.width() {
+				Some(width) => write_padded(f, &format!("{report:#}"), width),
+				None => write!(f, "{report:#}"),
+			};
+		}
+
+		// Support width, fill and alignment for the single-line format by buffering it.
+		if f.alternate()
+			&& let Some(width) = f.width()
+		{
+			return write_padded(f, &format!("{report:#}"), width);
+		}
+
+		self.render(f)
+	}
+}
+
+impl Report<'_> {
+	/// Render the error according to the formatter's flags.
+	fn render(&self, f: &mut Formatter<'_>) -> FmtResult {
+		let show_locations = self.effective_location_style() != LocationStyle::Hidden;
+		let symbols = self.symbols();
+
+		#[cfg(feature = "timestamp")]
+		if let Some(created_at) =
+			self.error.attachment::<crate::CreatedAt>().filter(|_| self.options.timestamp)
+		{
+			write!(f, "{created_at} ")?;
+		}
+
+		// The error code prefixes the top message.
+		if let Some(ErrorCode(code)) = self.error.attachment::<ErrorCode>() {
+			write!(f, "[{code}] ")?;
+		}
+
+		// Fast path for the most common error consisting of a single message only.
+		if let Some(context) = self.error.single_context().filter(|_| {
+			!f.alternate() && !self.options.legend && self.options.max_contexts != Some(0)
+		}) {
+			return self.write_single(f, context);
+		}
+
+		// Whether more entries follow on the top level after each section.
+		let displayed = self.displayed_attachments();
+		let mut fields = self.error.attachments::<Fields>().flat_map(Fields::iter).peekable();
+		#[cfg(feature = "backtrace")]
+		let backtrace = self.error.backtrace().filter(|_| !f.alternate() && show_locations);
+		#[cfg(feature = "backtrace")]
+		let after_fields = self.continued || backtrace.is_some();
+		#[cfg(not(feature = "backtrace"))]
+		let after_fields = self.continued;
+		let after_attachments = after_fields || fields.peek().is_some();
+		let after_sources = after_attachments || !displayed.is_empty();
+		let after_contexts = after_sources || self.error.source_chain_len() > 0;
+
+		let mut human = self.error.contexts().enumerate().peekable();
+		let headless = human.peek().is_none();
+		if headless && self.error.source_chain_len() == 0 {
+			#[cfg(feature = "colors")]
+			let unknown = self.options.unknown_message.red().whenever(self.colors());
+			#[cfg(not(feature = "colors"))]
+			let unknown = self.options.unknown_message;
+
+			write!(f, "{unknown}")?;
+		}
+		while let Some((i, context)) = human.next() {
+			if self.options.max_contexts.is_some_and(|max| i >= max) {
+				let remaining = 1 + human.count();
+				write!(f, "... ({remaining} more contexts)")?;
+				break;
+			}
+
+			#[cfg(feature = "colors")]
+			let message = context.message.as_ref().red().whenever(self.colors());
+			#[cfg(not(feature = "colors"))]
+			let message = context.message.as_ref();
+
+			let location = self.location(context.location);
+			#[cfg(feature = "colors")]
+			let location = location.rgb(0x90, 0x90, 0x90).whenever(self.colors());
+
+			if f.alternate() {
+				write!(f, "{message}")?;
+				if show_locations {
+					write!(f, " (at {location})")?;
+				}
+				if human.peek().is_some() {
+					write!(f, "{}", self.options.separator)?;
+				}
+			} else {
+				write!(f, "{message}")?;
+				if show_locations {
+					let last = !after_contexts && human.peek().is_none();
+					write!(f, "\n{}at {location}", self.branch(last))?;
+				}
+				if human.peek().is_some() {
+					write!(f, "\n{}\n", symbols.line)?;
+				}
+			}
+		}
+
+		self.write_sources(f, headless, after_sources)?;
+
+		self.write_attachments(f, &displayed, after_attachments)?;
+
+		if fields.peek().is_some() {
+			if f.alternate() {
+				write!(f, "{}fields: ", self.options.separator)?;
+			} else {
+				write!(f, "\n{}\n{}fields: ", symbols.line, self.branch(!after_fields))?;
+			}
+			while let Some((key, value)) = fields.next() {
+				write!(f, "{key}={value}")?;
+				if fields.peek().is_some() {
+					write!(f, ", ")?;
+				}
+			}
+		}
+
+		self.write_hints(f)?;
+
+		#[cfg(feature = "backtrace")]
+		if let Some(backtrace) = backtrace {
+			write!(f, "\n{}\n{}backtrace:", symbols.line, self.branch(!self.continued))?;
+			write!(Indented { f, indent: &self.indent(1, self.continued) }, "\n{backtrace}")?;
+		}
+
+		if self.options.legend && !f.alternate() {
+			writeln!(f)?;
+			writeln!(f)?;
+			write!(f, "({})", symbols.legend)?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Whether the `NEUER_ERROR_HIDE_LOCATIONS` environment variable is set to `1`. It is only read
+/// once.
+#[cfg(feature = "std")]
+fn locations_hidden_by_env() -> bool {
+	/// Cached value of the environment variable check.
+	static HIDDEN: ::std::sync::OnceLock<bool> = ::std::sync::OnceLock::new();
+	*HIDDEN
+		.get_or_init(|| hide_locations(::std::env::var_os("NEUER_ERROR_HIDE_LOCATIONS").as_deref()))
+}
+
+/// Whether the given value of the `NEUER_ERROR_HIDE_LOCATIONS` environment variable hides
+/// locations, which is only the case for `1`.
+#[cfg(feature = "std")]
+pub(crate) fn hide_locations(value: Option<&::std::ffi::OsStr>) -> bool {
+	value.is_some_and(|value| value == "1")
+}
+
+/// Write the text padded to the given width according to the formatter's fill and alignment.
+/// Unlike [`Formatter::pad`], ANSI escape sequences (colors) do not count towards the width.
+fn write_padded(f: &mut Formatter<'_>, text: &str, width: usize) -> FmtResult {
+	let mut measure = Measure::default();
+	measure.write_str(text)?;
+	let padding = width.saturating_sub(measure.max_width);
+	let (before, after) = match f.align() {
+		None | Some(Alignment::Left) => (0, padding),
+		Some(Alignment::Right) => (padding, 0),
+		Some(Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+	};
+
+	let fill = f.fill();
+	for _ in 0 .. before {
+		f.write_char(fill)?;
+	}
+	f.write_str(text)?;
+	for _ in 0 .. after {
+		f.write_char(fill)?;
+	}
+	Ok(())
+}
+
+
+/// `fmt::Write` adapter that indents all lines but the first.
+struct Indented<'a, 'b> {
+	/// Formatter to write to.
+	f: &'a mut Formatter<'b>,
+	/// Indentation to insert after line breaks.
+	indent: &'a str,
+}
+
+impl Write for Indented<'_, '_> {
+	fn write_str(&mut self, s: &str) -> FmtResult {
+		let mut lines = s.split('\n');
+		if let Some(first) = lines.next() {
+			self.f.write_str(first)?;
+		}
+		for line in lines {
+			self.f.write_char('\n')?;
+			self.f.write_str(self.indent)?;
+			self.f.write_str(line)?;
+		}
+		Ok(())
+	}
+}
+
+/// State of the ANSI escape sequence parser of [`Measure`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum EscapeState {
+	/// Normal text.
+	#[default]
+	Text,
+	/// After the escape character.
+	Escape,
+	/// Within a control sequence.
+	ControlSequence,
+}
+
+impl EscapeState {
+	/// Advance the parser by one character. Returns the new state and whether the character is
+	/// visible text.
+	const fn advance(self, c: char) -> (Self, bool) {
+		match (self, c) {
+			(Self::Text, '\x1B') => (Self::Escape, false),
+			(Self::Text, _) => (Self::Text, true),
+			(Self::Escape, '[') => (Self::ControlSequence, false),
+			(Self::Escape, _) => (Self::Text, false),
+			(Self::ControlSequence, '\x40' ..= '\x7E') => (Self::Text, false),
+			(Self::ControlSequence, _) => (Self::ControlSequence, false),
+		}
+	}
+}
+
+/// `fmt::Write` sink that only measures the number of lines and the maximum line width of the
+/// written text in characters, ignoring ANSI escape sequences (colors).
+#[derive(Debug, Default)]
+struct Measure {
+	/// Number of line breaks.
+	line_breaks: usize,
+	/// Width of the current line.
+	width: usize,
+	/// Maximum width of all lines.
+	max_width: usize,
+	/// Escape sequence parsing state.
+	escape: EscapeState,
+}
+
+impl Write for Measure {
+	fn write_str(&mut self, s: &str) -> FmtResult {
+		for c in s.chars() {
+			let (escape, visible) = self.escape.advance(c);
+			self.escape = escape;
+			if !visible {
+				continue;
+			}
+			if c == '\n' {
+				self.line_breaks += 1;
+				self.width = 0;
+			} else {
+				self.width += 1;
+				self.max_width = self.max_width.max(self.width);
+			}
+		}
+		Ok(())
+	}
+}
+
+impl NeuErr {
+	/// Measure the size the rendered error will occupy, without allocating the rendered string.
+	/// Returns the number of lines and the maximum line width in characters, in this order.
+	///
+	/// Set `compact` to measure the compact single-line format (`"{err:#}"`) instead of the
+	/// multi-line format. Colors are not counted. Note that characters are counted as one column,
+	/// regardless of their actual display width.
+	#[must_use]
+	pub fn rendered_size(&self, compact: bool) -> (usize, usize) {
+		let mut measure = Measure::default();
+		let report = self.as_report();
+		// Measuring never fails, but the error's contents could fail formatting, which we ignore.
+		_ = if compact { write!(measure, "{report:#}") } else { write!(measure, "{report}") };
+		(measure.line_breaks + 1, measure.max_width)
+	}
+
+	/// Write the rendered error directly into the `io` writer, without allocating an intermediate
+	/// string.
+	///
+	/// Set `compact` to write the compact single-line format (`"{err:#}"`) instead of the
+	/// multi-line format. Colors are included if enabled.
+	#[cfg(feature = "std")]
+	pub fn write_to<W>(&self, writer: &mut W, compact: bool) -> ::std::io::Result<()>
+	where
+		W: ::std::io::Write + ?Sized,
+	{
+		let report = self.as_report();
+		if compact { report.compact().write_to(writer) } else { report.write_to(writer) }
+	}
+
+	/// Split the error into separate strings, top to bottom: the human context messages, followed
+	/// by the `Display` output of each source error, e.g. for JSON log fields. Locations are not
+	/// included, use [`Report::chain_messages`] to include them.
+	///
+	/// ```
+	/// # use neuer_error::NeuErr;
+	/// let error = NeuErr::new_with_source("Inner", std::fmt::Error).context("Outer");
+	/// assert_eq!(
+	/// 	error.chain_messages(),
+	/// 	["Outer", "Inner", "an error occurred when formatting an argument"]
+	/// );
+	/// ```
+	#[must_use]
+	pub fn chain_messages(&self) -> Vec<String> {
+		self.as_report().location_style(LocationStyle::Hidden).chain_messages()
+	}
+}
+
+impl Report<'_> {
+	/// Split the error into separate strings, top to bottom: the human context messages with their
+	/// locations in the configured style, like `"Outer (at src/main.rs:5:9)"`, followed by the
+	/// `Display` output of each source error. Nested errors in the source chain are split into
+	/// their human context messages as well.
+	#[must_use]
+	pub fn chain_messages(&self) -> Vec<String> {
+		let mut messages = Vec::new();
+		self.push_context_messages(self.error, &mut messages);
+		for source in self.error.chain() {
+			match source.downcast_ref::<NeuErrImpl>() {
+				Some(nested) => self.push_context_messages(nested, &mut messages),
+				None => messages.push(source.to_string()),
+			}
+		}
+		messages
+	}
+
+	/// Push the human context messages of the error with their locations, newest first.
+	fn push_context_messages(&self, error: &NeuErrImpl, messages: &mut Vec<String>) {
+		let show_locations = self.effective_location_style() != LocationStyle::Hidden;
+		messages.extend(error.contexts().map(|context| {
+			if show_locations {
+				format!("{} (at {})", context.message, self.location(context.location))
+			} else {
+				context.message.to_string()
+			}
+		}));
+	}
+
+	/// Write the rendered error with the configured options directly into the `io` writer, without
+	/// allocating an intermediate string, e.g. in hot logging paths.
+	#[cfg(feature = "std")]
+	pub fn write_to<W>(&self, writer: &mut W) -> ::std::io::Result<()>
+	where
+		W: ::std::io::Write + ?Sized,
+	{
+		write!(writer, "{self}")
+	}
+}
@@ -1,6 +1,7 @@
 //! Helpers on `Result` types for conversion and context addition.
 
-use ::alloc::borrow::Cow;
+use ::alloc::{borrow::Cow, vec::Vec};
+use ::core::fmt::Display;
 
 use crate::{
 	NeuErr,
@@ -24,6 +25,46 @@ pub trait CtxResultExt: Sized {
 		F: FnOnce() -> C,
 		C: Into<Cow<'static, str>>;
 
+	/// Add human context to the error via a closure receiving the current error, e.g. to base the
+	/// message on the existing top message or an attachment.
+	#[track_caller]
+	#[must_use]
+	fn context_with_err<F, C>(self, context_fn: F) -> Self
+	where
+		F: FnOnce(&NeuErr) -> C,
+		C: Into<Cow<'static, str>>;
+
+	/// Add human context to the error via a fallible closure. If the closure returns `None`, the
+	/// error is left unchanged.
+	#[track_caller]
+	#[must_use]
+	fn context_try_with<F, C>(self, context_fn: F) -> Self
+	where
+		F: FnOnce() -> Option<C>,
+		C: Into<Cow<'static, str>>;
+
+	/// Add human context of a numbered step in a pipeline to the error, formatted like
+	/// `"step 2/5: {context}"`.
+	#[track_caller]
+	#[must_use]
+	fn context_step<C>(self, step: usize, total: usize, context: C) -> Self
+	where
+		C: Display;
+
+	/// Add a human-facing note to the error, rendered at the end of the error, see
+	/// [`NeuErr::note`].
+	#[must_use]
+	fn note<N>(self, note: N) -> Self
+	where
+		N: Into<Cow<'static, str>>;
+
+	/// Add a human-facing help with a suggestion how to fix the error, rendered at the end of the
+	/// error, see [`NeuErr::help`].
+	#[must_use]
+	fn help<H>(self, help: H) -> Self
+	where
+		H: Into<Cow<'static, str>>;
+
 	/// Add machine context to the error.
 	///
 	/// This will not override existing attachments. If you want to replace and override any
@@ -61,6 +102,26 @@ pub trait CtxResultExt: Sized {
 	where
 		F: FnOnce() -> C,
 		C: AnyDebugSendSync + 'static;
+
+	/// Call the closure with the error, e.g. to log it while still propagating it.
+	///
+	/// ```
+	/// # use neuer_error::{traits::*, NeuErr, Result};
+	/// fn load() -> Result<()> {
+	/// 	Err(NeuErr::new("File not found"))
+	/// }
+	///
+	/// let mut logged = Vec::new();
+	/// let result = load()
+	/// 	.context("Failed loading config")
+	/// 	.inspect_context(|err| logged.push(format!("{err:#}")));
+	/// assert!(result.is_err());
+	/// assert_eq!(logged.len(), 1);
+	/// ```
+	#[must_use]
+	fn inspect_context<F>(self, inspect_fn: F) -> Self
+	where
+		F: FnOnce(&NeuErr);
 }
 
 impl<T> CtxResultExt for Result<T, NeuErr> {
@@ -91,6 +152,66 @@ impl<T> CtxResultExt for Result<T, NeuErr> {
 		}
 	}
 
+	#[track_caller]
+	#[inline]
+	fn context_with_err<F, C>(self, context_fn: F) -> Self
+	where
+		F: FnOnce(&NeuErr) -> C,
+		C: Into<Cow<'static, str>>,
+	{
+		// Cannot use `map_err` because closures cannot have `#[track_caller]` yet.
+		match self {
+			Ok(value) => Ok(value),
+			Err(err) => {
+				let context = context_fn(&err);
+				Err(err.context(context))
+			}
+		}
+	}
+
+	#[track_caller]
+	#[inline]
+	fn context_try_with<F, C>(self, context_fn: F) -> Self
+	where
+		F: FnOnce() -> Option<C>,
+		C: Into<Cow<'static, str>>,
+	{
+		// Cannot use `map_err` because closures cannot have `#[track_caller]` yet.
+		match self {
+			Ok(value) => Ok(value),
+			Err(err) => Err(err.context_try_with(context_fn)),
+		}
+	}
+
+	#[track_caller]
+	#[inline]
+	fn context_step<C>(self, step: usize, total: usize, context: C) -> Self
+	where
+		C: Display,
+	{
+		// Cannot use `map_err` because closures cannot have `#[track_caller]` yet.
+		match self {
+			Ok(value) => Ok(value),
+			Err(err) => Err(err.context_step(step, total, context)),
+		}
+	}
+
+	#[inline]
+	fn note<N>(self, note: N) -> Self
+	where
+		N: Into<Cow<'static, str>>,
+	{
+		self.map_err(|err| err.note(note))
+	}
+
+	#[inline]
+	fn help<H>(self, help: H) -> Self
+	where
+		H: Into<Cow<'static, str>>,
+	{
+		self.map_err(|err| err.help(help))
+	}
+
 	#[inline]
 	fn attach<C>(self, context: C) -> Self
 	where
@@ -124,6 +245,14 @@ impl<T> CtxResultExt for Result<T, NeuErr> {
 	{
 		self.map_err(|err| err.attach_override(context_fn()))
 	}
+
+	#[inline]
+	fn inspect_context<F>(self, inspect_fn: F) -> Self
+	where
+		F: FnOnce(&NeuErr),
+	{
+		self.inspect_err(inspect_fn)
+	}
 }
 
 
@@ -142,6 +271,13 @@ pub trait ConvertResult<T, E>: Sized {
 		F: FnOnce(&E) -> C,
 		C: Into<Cow<'static, str>>;
 
+	/// Add human context of a numbered step in a pipeline to the error, formatted like
+	/// `"step 2/5: {context}"`.
+	#[track_caller]
+	fn context_step<C>(self, step: usize, total: usize, context: C) -> Result<T, NeuErr>
+	where
+		C: Display;
+
 	/// Add machine context to the error.
 	///
 	/// This will not override existing attachments. If you want to replace and override any
@@ -175,6 +311,11 @@ pub trait ConvertResult<T, E>: Sized {
 	where
 		F: FnOnce(&E) -> C,
 		C: AnyDebugSendSync + 'static;
+
+	/// Convert the error and call the closure with it, e.g. to log it while still propagating it.
+	fn inspect_context<F>(self, inspect_fn: F) -> Result<T, NeuErr>
+	where
+		F: FnOnce(&NeuErr);
 }
 
 impl<T, E> ConvertResult<T, E> for Result<T, E>
@@ -211,6 +352,19 @@ where
 		}
 	}
 
+	#[track_caller]
+	#[inline]
+	fn context_step<C>(self, step: usize, total: usize, context: C) -> Result<T, NeuErr>
+	where
+		C: Display,
+	{
+		// Cannot use `map_err` because closures cannot have `#[track_caller]` yet.
+		match self {
+			Ok(value) => Ok(value),
+			Err(err) => Err(NeuErr::from_source(err).context_step(step, total, context)),
+		}
+	}
+
 	#[inline]
 	fn attach<C>(self, context: C) -> Result<T, NeuErr>
 	where
@@ -250,6 +404,14 @@ where
 			NeuErr::from_source(err).attach_override(attach)
 		})
 	}
+
+	#[inline]
+	fn inspect_context<F>(self, inspect_fn: F) -> Result<T, NeuErr>
+	where
+		F: FnOnce(&NeuErr),
+	{
+		self.map_err(NeuErr::from_source).inspect_err(inspect_fn)
+	}
 }
 
 
@@ -376,12 +538,75 @@ impl<T> ConvertOption<T> for Option<T> {
 }
 
 
+/// Run through all `Result`s of the iterator without short-circuiting, collecting the successful
+/// values and the errors separately, each in their original order.
+///
+/// ```
+/// # use neuer_error::{NeuErr, partition_results};
+/// let results = ["1", "x", "3", "y"].into_iter().map(|input| input.parse::<u8>());
+/// let (values, errors): (Vec<u8>, Vec<NeuErr>) = partition_results(results);
+/// assert_eq!(values, [1, 3]);
+/// assert_eq!(errors.len(), 2);
+/// ```
+pub fn partition_results<I, T, E>(iter: I) -> (Vec<T>, Vec<NeuErr>)
+where
+	I: IntoIterator<Item = Result<T, E>>,
+	E: Into<NeuErr>,
+{
+	let mut values = Vec::new();
+	let mut errors = Vec::new();
+	for result in iter {
+		match result {
+			Ok(value) => values.push(value),
+			Err(err) => errors.push(err.into()),
+		}
+	}
+	(values, errors)
+}
+
+
 /// Helpers on `Result`s.
 pub trait ResultExt<T, E> {
 	/// Consumes the error from the `Result` and pushes it into the provided collection.
 	fn or_collect<C>(self, collection: &mut C) -> Option<T>
 	where
 		C: Extend<E>;
+
+	/// Consumes the error from the `Result` and pushes it into the provided errors, returning the
+	/// new number of errors. Allows to stop early after too many errors:
+	///
+	/// ```
+	/// # use neuer_error::{traits::*, NeuErr};
+	/// let mut errors = Vec::new();
+	/// for input in ["x", "1", "y", "z"] {
+	/// 	if let Err(count) = input.parse::<u8>().or_collect_count(&mut errors)
+	/// 		&& count >= 2
+	/// 	{
+	/// 		break;
+	/// 	}
+	/// }
+	/// assert_eq!(errors.len(), 2);
+	/// ```
+	fn or_collect_count(self, errors: &mut Vec<E>) -> Result<T, usize>;
+
+	/// Applies the fallible function to the contained value, like `and_then`, and adds the human
+	/// context to the error of whichever step failed.
+	#[track_caller]
+	fn and_then_ctx<U, F, C>(self, context: C, f: F) -> Result<U, NeuErr>
+	where
+		E: Into<NeuErr>,
+		F: FnOnce(T) -> Result<U, NeuErr>,
+		C: Into<Cow<'static, str>>;
+
+	/// Returns the contained value or logs the error and returns the default value instead.
+	///
+	/// The error is logged on error level in the multi-line format. When both the `tracing` and
+	/// the `log` feature are enabled, it is emitted via `tracing` only.
+	#[cfg(any(feature = "log", feature = "tracing"))]
+	fn unwrap_or_default_logged(self) -> T
+	where
+		T: Default,
+		E: Display;
 }
 
 impl<T, E> ResultExt<T, E> for Result<T, E> {
@@ -398,4 +623,50 @@ impl<T, E> ResultExt<T, E> for Result<T, E> {
 			}
 		}
 	}
+
+	#[inline]
+	fn or_collect_count(self, errors: &mut Vec<E>) -> Result<T, usize> {
+		self.map_err(|err| {
+			errors.push(err);
+			errors.len()
+		})
+	}
+
+	#[track_caller]
+	#[inline]
+	fn and_then_ctx<U, F, C>(self, context: C, f: F) -> Result<U, NeuErr>
+	where
+		E: Into<NeuErr>,
+		F: FnOnce(T) -> Result<U, NeuErr>,
+		C: Into<Cow<'static, str>>,
+	{
+		// Cannot use `map_err` because closures cannot have `#[track_caller]` yet.
+		let result = match self {
+			Ok(value) => f(value),
+			Err(err) => Err(err.into()),
+		};
+		match result {
+			Ok(value) => Ok(value),
+			Err(err) => Err(err.context(context)),
+		}
+	}
+
+	#[cfg(any(feature = "log", feature = "tracing"))]
+	#[inline]
+	fn unwrap_or_default_logged(self) -> T
+	where
+		T: Default,
+		E: Display,
+	{
+		match self {
+			Ok(value) => value,
+			Err(err) => {
+				#[cfg(feature = "tracing")]
+				::tracing::error!("{err}");
+				#[cfg(not(feature = "tracing"))]
+				::log::error!("{err}");
+				T::default()
+			}
+		}
+	}
 }
@@ -0,0 +1,72 @@
+//! Integration with `sentry` for capturing errors as events.
+
+use ::sentry_core::{
+	event_from_error,
+	protocol::{Breadcrumb, Event, Exception, Level, Map, Uuid, Value},
+};
+
+use crate::{NeuErr, error::Info};
+
+impl NeuErr {
+	/// Build a `sentry` event from the error.
+	///
+	/// - The newest human context message is used as value of the outermost exception.
+	/// - The source error chain is added as chained exceptions.
+	/// - All human contexts are added as breadcrumbs, including their file and line.
+	/// - Attachments are added as extra data, keyed by their type name with their `Debug` string as
+	///   value. For multiple attachments of the same type, only the newest is added.
+	#[must_use]
+	pub fn to_sentry_event(&self) -> Event<'static> {
+		let mut event = self.0.chain().next().map(event_from_error).unwrap_or_default();
+		event.level = Level::Error;
+		event.exception.values.push(Exception {
+			ty: "NeuErr".to_owned(),
+			value: self.0.contexts().next().map(|context| context.message.clone().into_owned()),
+			..Default::default()
+		});
+
+		let mut breadcrumbs = Vec::new();
+		let mut extra = Map::new();
+		for info in self.0.infos() {
+			match info {
+				Info::Human(context) => {
+					let mut data = Map::new();
+					data.insert("file".to_owned(), context.location.file().into());
+					data.insert("line".to_owned(), context.location.line().into());
+					breadcrumbs.push(Breadcrumb {
+						category: Some("error.context".to_owned()),
+						level: Level::Error,
+						message: Some(context.message.clone().into_owned()),
+						data,
+						..Default::default()
+					});
+				}
+				Info::Machine(info) => {
+					let attachment = info.attachment.as_ref();
+					extra
+						.entry(info.type_name().to_owned())
+						.or_insert_with(|| Value::String(format!("{attachment:?}")));
+				}
+			}
+		}
+		// Breadcrumbs are chronological, so the oldest context comes first.
+		breadcrumbs.reverse();
+		event.breadcrumbs = breadcrumbs.into();
+		event.extra = extra;
+
+		event
+	}
+
+	/// Capture the error as `sentry` event via the current hub. See
+	/// [`to_sentry_event`](Self::to_sentry_event) for how the event is built.
+	///
+	/// Returns the ID of the captured event.
+	#[inline]
+	#[expect(
+		clippy::must_use_candidate,
+		reason = "Capturing is the main purpose, the ID is optional"
+	)]
+	pub fn capture_sentry(&self) -> Uuid {
+		::sentry_core::capture_event(self.to_sentry_event())
+	}
+}
@@ -0,0 +1,164 @@
+//! Integration with `serde` for serializing errors, e.g. for structured logging.
+
+use ::core::error::Error;
+use ::serde::{Serialize, Serializer, ser::SerializeStruct};
+
+use crate::{
+	NeuErr, NeuErrImpl,
+	error::{AttachmentHooks, HumanInfo, Info, MachineInfo},
+	features::AnyDebugSendSync,
+};
+
+impl NeuErr {
+	/// Add machine context to the error, which is serialized by its value when serializing the
+	/// error. Other attachments are serialized by their `Debug` string.
+	#[must_use]
+	#[inline]
+	pub fn attach_serialize<C>(self, context: C) -> Self
+	where
+		C: AnyDebugSendSync + Serialize + 'static,
+	{
+		self.attach_with_hooks(context, AttachmentHooks::serialized::<C>())
+	}
+}
+
+impl NeuErrImpl {
+	/// Add machine context to the error, which is serialized by its value when serializing the
+	/// error. Other attachments are serialized by their `Debug` string.
+	#[must_use]
+	#[inline]
+	pub fn attach_serialize<C>(self, context: C) -> Self
+	where
+		C: AnyDebugSendSync + Serialize + 'static,
+	{
+		self.attach_with_hooks(context, AttachmentHooks::serialized::<C>())
+	}
+}
+
+impl Serialize for NeuErr {
+	/// See the implementation on [`NeuErrImpl`].
+	#[inline]
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		self.0.serialize(serializer)
+	}
+}
+
+impl Serialize for NeuErrImpl {
+	/// Serializes the error in the following stable shape, newest entries first:
+	///
+	/// ```json
+	/// {
+	/// 	"contexts": [{ "message": "Failed", "file": "src/main.rs", "line": 5, "column": 9 }],
+	/// 	"source": ["Immediate source error", "Source of the source error"],
+	/// 	"attachments": [{ "type": "my_crate::Retryable", "value": "No" }]
+	/// }
+	/// ```
+	///
+	/// Attachments added via [`NeuErr::attach_serialize`] are serialized by their value, all other
+	/// attachments by their `Debug` string.
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let mut state = serializer.serialize_struct("NeuErr", 3)?;
+		state.serialize_field("contexts", &Contexts(self))?;
+		state.serialize_field("source", &Sources(self))?;
+		state.serialize_field("attachments", &Attachments(self))?;
+		state.end()
+	}
+}
+
+/// Serializes the human context infos of an error.
+struct Contexts<'a>(&'a NeuErrImpl);
+
+impl Serialize for Contexts<'_> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.collect_seq(self.0.contexts())
+	}
+}
+
+impl Serialize for HumanInfo {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let mut state = serializer.serialize_struct("Context", 4)?;
+		state.serialize_field("message", &self.message)?;
+		state.serialize_field("file", self.location.file())?;
+		state.serialize_field("line", &self.location.line())?;
+		state.serialize_field("column", &self.location.column())?;
+		state.end()
+	}
+}
+
+/// Serializes the source error chain of an error as strings.
+struct Sources<'a>(&'a NeuErrImpl);
+
+impl Serialize for Sources<'_> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.collect_seq(self.0.chain().map(Displayed))
+	}
+}
+
+/// Serializes a value as string via its `Display` implementation.
+struct Displayed<'a>(&'a (dyn Error + 'static));
+
+impl Serialize for Displayed<'_> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.collect_str(self.0)
+	}
+}
+
+/// Serializes the machine context attachments of an error.
+struct Attachments<'a>(&'a NeuErrImpl);
+
+impl Serialize for Attachments<'_> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.collect_seq(self.0.infos().filter_map(|info| match info {
+			Info::Machine(info) => Some(info),
+			Info::Human(_) => None,
+		}))
+	}
+}
+
+impl Serialize for MachineInfo {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let mut state = serializer.serialize_struct("Attachment", 2)?;
+		state.serialize_field("type", self.type_name())?;
+		match self.serializable() {
+			Some(value) => state.serialize_field("value", value)?,
+			None => state.serialize_field("value", &DebugString(self.attachment.as_ref()))?,
+		}
+		state.end()
+	}
+}
+
+/// Serializes a value as string via its `Debug` implementation.
+struct DebugString<'a>(&'a dyn AnyDebugSendSync);
+
+impl Serialize for DebugString<'_> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.collect_str(&format_args!("{:?}", self.0))
+	}
+}
@@ -0,0 +1,122 @@
+//! Utilities for testing errors.
+
+use ::alloc::{
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
+use ::core::fmt::Write;
+
+use crate::{NeuErr, error::Info, features::AnyDebugSendSync};
+
+impl NeuErr {
+	/// Describe the differences to another error in a human-readable way, e.g. to make failing
+	/// assertions in tests actionable. Returns an empty string if there are no differences.
+	///
+	/// Compares the human context messages, the attachments (by type name and `Debug` output) and
+	/// the source error chain (by `Display` output), each newest first. Locations are ignored.
+	///
+	/// ```
+	/// # use neuer_error::NeuErr;
+	/// let error = NeuErr::new("inner").context("outer");
+	/// let expected = NeuErr::new("inner").context("other");
+	/// assert_eq!(error.diff(&expected), "context 0: \"outer\" != \"other\"\n");
+	/// ```
+	#[must_use]
+	pub fn diff(&self, other: &Self) -> String {
+		let mut diff = String::new();
+		diff_section(&mut diff, "context", &contexts(self), &contexts(other));
+		diff_section(&mut diff, "attachment", &attachments(self), &attachments(other));
+		diff_section(&mut diff, "source", &sources(self), &sources(other));
+		diff
+	}
+
+	/// Whether both errors have the same human context messages, ignoring locations, attachments
+	/// and source errors.
+	///
+	/// ```
+	/// # use neuer_error::NeuErr;
+	/// let error = NeuErr::new("inner").context("outer");
+	/// assert!(error.eq_messages(&NeuErr::new("inner").context("outer")));
+	/// ```
+	#[must_use]
+	pub fn eq_messages(&self, other: &Self) -> bool {
+		self.messages().eq(other.messages())
+	}
+
+	/// Whether the error has an attachment of the given type.
+	///
+	/// ```
+	/// # use neuer_error::NeuErr;
+	/// assert!(NeuErr::new("test").attach(5_u8).has_attachment::<u8>());
+	/// ```
+	#[must_use]
+	pub fn has_attachment<C>(&self) -> bool
+	where
+		C: AnyDebugSendSync + 'static,
+	{
+		self.attachment::<C>().is_some()
+	}
+
+	/// Whether the error has an attachment of the given type that is equal to the given value.
+	///
+	/// ```
+	/// # use neuer_error::NeuErr;
+	/// assert!(NeuErr::new("test").attach(5_u8).attach(6_u8).has_attachment_eq(&5_u8));
+	/// ```
+	#[must_use]
+	pub fn has_attachment_eq<C>(&self, value: &C) -> bool
+	where
+		C: AnyDebugSendSync + PartialEq + 'static,
+	{
+		self.attachments::<C>().any(|attachment| attachment == value)
+	}
+}
+
+/// Errors are equal if they have the same human context messages and locations. Attachments and
+/// source errors are ignored.
+impl PartialEq for NeuErr {
+	fn eq(&self, other: &Self) -> bool {
+		self.0
+			.contexts()
+			.map(|context| (&context.message, context.location))
+			.eq(other.0.contexts().map(|context| (&context.message, context.location)))
+	}
+}
+
+/// Human context messages of the error, newest first.
+fn contexts(error: &NeuErr) -> Vec<String> {
+	error.0.contexts().map(|context| format!("{:?}", context.message)).collect()
+}
+
+/// Attachments of the error with type name and `Debug` output, newest first. Automatically attached
+/// infos like the creation time are skipped, as they differ between otherwise equal errors.
+fn attachments(error: &NeuErr) -> Vec<String> {
+	error
+		.0
+		.infos()
+		.filter_map(|info| match info {
+			Info::Machine(info) if !info.is_automatic() => Some(info.attachment.as_ref()),
+			Info::Machine(_) | Info::Human(_) => None,
+		})
+		.map(|attachment| format!("{}({attachment:?})", attachment.type_name()))
+		.collect()
+}
+
+/// Source error chain of the error, starting with the immediate source.
+fn sources(error: &NeuErr) -> Vec<String> {
+	error.0.chain().map(ToString::to_string).collect()
+}
+
+/// Write the differences between the two lists of items as lines to the output.
+fn diff_section(output: &mut String, name: &str, left: &[String], right: &[String]) {
+	for i in 0 .. left.len().max(right.len()) {
+		let (left, right) = (left.get(i), right.get(i));
+		if left != right {
+			let left = left.map_or("<missing>", String::as_str);
+			let right = right.map_or("<missing>", String::as_str);
+			// Writing to a `String` never fails.
+			_ = writeln!(output, "{name} {i}: {left} != {right}");
+		}
+	}
+}
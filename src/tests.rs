@@ -1,6 +1,12 @@
 //! Crate tests.
 
-use ::alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+use ::alloc::{
+	borrow::{Cow, ToOwned},
+	boxed::Box,
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
 use ::core::{
 	error::Error,
 	fmt::{Display, Formatter, Result as FmtResult},
@@ -23,9 +29,28 @@ fn remove_colors(s: &str) -> String {
 	s.to_owned()
 }
 
+/// `NeuErr` is a thin pointer to keep `Result`s small.
+#[test]
+fn size() {
+	assert_eq!(size_of::<NeuErr>(), size_of::<usize>());
+	assert_eq!(size_of::<Result<()>>(), size_of::<usize>());
+	assert_eq!(size_of::<Result<usize>>(), 2 * size_of::<usize>());
+}
+
+/// Remove the attachments added automatically at creation, e.g. with the `timestamp` feature.
+fn without_automatic(mut error: NeuErr) -> NeuErr {
+	error.0.remove_automatic();
+	error
+}
+
+/// Remove the backtrace, which is printed if enabled via environment variables.
+fn remove_backtrace(s: &str) -> &str {
+	s.split_once("\n|\n|- backtrace:").map_or(s, |(error, _)| error)
+}
+
 #[test]
 fn debug_impl() {
-	let error = level2().unwrap_err().attach(0);
+	let error = without_automatic(level2().unwrap_err().attach(0));
 	let normal = remove_colors(&format!("{error:?}"));
 	let alternate = remove_colors(&format!("{error:#?}"));
 
@@ -98,6 +123,330 @@ fn display_impl() {
 	assert!(matcher.is_match(&alternate), "Found: {alternate}");
 }
 
+#[test]
+fn report_location_style() {
+	let error = NeuErr::new("inner").context("outer");
+	assert_eq!(format!("{error}"), format!("{}", error.as_report()));
+	assert_eq!(format!("{error:#}"), format!("{:#}", error.as_report()));
+
+	let full =
+		remove_colors(&format!("{:#}", error.as_report().location_style(LocationStyle::Full)));
+	let matcher =
+		Regex::new(r"^outer \(at src/tests\.rs:\d+:\d+\); inner \(at src/tests\.rs:\d+:\d+\)$")
+			.expect("failed compiling regex");
+	assert!(matcher.is_match(&full), "Found: {full}");
+
+	let file_name =
+		remove_colors(&format!("{:#}", error.as_report().location_style(LocationStyle::FileName)));
+	let matcher = Regex::new(r"^outer \(at tests\.rs:\d+:\d+\); inner \(at tests\.rs:\d+:\d+\)$")
+		.expect("failed compiling regex");
+	assert!(matcher.is_match(&file_name), "Found: {file_name}");
+
+	let file_line =
+		remove_colors(&format!("{:#}", error.as_report().location_style(LocationStyle::FileLine)));
+	let matcher = Regex::new(r"^outer \(at tests\.rs:\d+\); inner \(at tests\.rs:\d+\)$")
+		.expect("failed compiling regex");
+	assert!(matcher.is_match(&file_line), "Found: {file_line}");
+
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(remove_colors(&format!("{report:#}")), "outer; inner");
+	assert_eq!(remove_colors(&format!("{report}")), "outer\n|\ninner");
+}
+
+#[test]
+fn report_strip_path_prefix() {
+	let error = NeuErr::new("test");
+	let stripped = remove_colors(&format!("{:#}", error.as_report().strip_path_prefix("src")));
+	let matcher = Regex::new(r"^test \(at tests\.rs:\d+:\d+\)$").expect("failed compiling regex");
+	assert!(matcher.is_match(&stripped), "Found: {stripped}");
+
+	let unchanged = error.as_report().strip_path_prefix("examples/");
+	assert_eq!(format!("{unchanged:#}"), format!("{error:#}"));
+	let unchanged = error.as_report().strip_path_prefix("sr");
+	assert_eq!(format!("{unchanged:#}"), format!("{error:#}"));
+	let stripped = remove_colors(&format!("{:#}", error.as_report().strip_path_prefix("src/")));
+	assert!(matcher.is_match(&stripped), "Found: {stripped}");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hide_locations_env() {
+	use ::std::ffi::OsStr;
+
+	use crate::report::hide_locations;
+
+	assert!(hide_locations(Some(OsStr::new("1"))));
+	assert!(!hide_locations(Some(OsStr::new("0"))));
+	assert!(!hide_locations(Some(OsStr::new(""))));
+	assert!(!hide_locations(None));
+
+	let hidden = hide_locations(::std::env::var_os("NEUER_ERROR_HIDE_LOCATIONS").as_deref());
+	let error = level1().unwrap_err();
+	let report = error.as_report().location_style(LocationStyle::Full);
+	let compact = remove_colors(&format!("{report:#}"));
+	assert_eq!(compact.contains(" (at "), !hidden, "Found: {compact}");
+	assert_eq!(remove_colors(&format!("{report}")).contains("|- at "), !hidden);
+	assert!(compact.starts_with("Level 1 error"));
+	assert!(compact.contains("caused by: SourceError occurred"));
+}
+
+#[test]
+fn format_options() {
+	let error = level2().unwrap_err();
+	assert_eq!(
+		format!("{}", error.display_with(&FormatOptions::default())),
+		format!("{}", error.as_report())
+	);
+
+	let options = FormatOptions {
+		location_style: LocationStyle::Hidden,
+		separator: " | ",
+		max_source_depth: Some(1),
+		..FormatOptions::default()
+	};
+	assert_eq!(
+		remove_colors(&format!("{:#}", error.display_with(&options))),
+		"Level 2 error | Level 1 error | Level 0 error | caused by: SourceError occurred | ... (1 \
+		 more)"
+	);
+	assert_eq!(
+		remove_colors(&format!("{}", error.display_with(&options))),
+		"Level 2 error\n|\nLevel 1 error\n|\nLevel 0 error\n|\n|- caused by: SourceError \
+		 occurred\n|\n|- ... (1 more)"
+	);
+}
+
+#[test]
+fn report_tree_style() {
+	let error = level2().unwrap_err();
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(
+		remove_colors(&format!("{}", report.tree_style(TreeStyle::Ascii))),
+		remove_colors(&format!("{report}"))
+	);
+	assert_eq!(
+		remove_colors(&format!("{}", report.tree_style(TreeStyle::Unicode).with_legend())),
+		"Level 2 error\n│\nLevel 1 error\n│\nLevel 0 error\n│\n└─ caused by: SourceError \
+		 occurred\n   │\n   └─ caused by: provided string was not `true` or `false`\n\n(├─ at = \
+		 source location, caused by: = underlying cause)"
+	);
+
+	let error = level1().unwrap_err().attach_display("first").attach_display("second");
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(
+		remove_colors(&format!("{}", report.tree_style(TreeStyle::Unicode))),
+		"Level 1 error\n│\nLevel 0 error\n│\n├─ caused by: SourceError occurred\n│  │\n│  └─ caused \
+		 by: provided string was not `true` or `false`\n│\n├─ second\n│\n└─ first"
+	);
+}
+
+#[test]
+fn report_indentation() {
+	let error = level2().unwrap_err();
+	let report = error
+		.as_report()
+		.location_style(LocationStyle::Hidden)
+		.tree_style(TreeStyle::Unicode)
+		.indentation("    ");
+	assert_eq!(
+		remove_colors(&format!("{report}")),
+		"Level 2 error\n│\nLevel 1 error\n│\nLevel 0 error\n│\n└─ caused by: SourceError \
+		 occurred\n    │\n    └─ caused by: provided string was not `true` or `false`"
+	);
+
+	let options = FormatOptions {
+		location_style: LocationStyle::Hidden,
+		tree_style: TreeStyle::Unicode,
+		indentation: Some("    "),
+		..FormatOptions::default()
+	};
+	assert_eq!(format!("{}", error.display_with(&options)), format!("{report}"));
+}
+
+#[test]
+fn report_max_contexts() {
+	let error = level2().unwrap_err();
+	let report = error.as_report().location_style(LocationStyle::Hidden).max_contexts(1);
+	assert_eq!(
+		remove_colors(&format!("{report:#}")),
+		"Level 2 error; ... (2 more contexts); caused by: SourceError occurred; caused by: provided \
+		 string was not `true` or `false`"
+	);
+	assert_eq!(
+		remove_colors(&format!("{}", report.max_source_depth(0))),
+		"Level 2 error\n|\n... (2 more contexts)\n|\n|- ... (2 more)"
+	);
+	assert_eq!(
+		format!("{:#}", report.max_contexts(3)),
+		format!("{:#}", error.as_report().location_style(LocationStyle::Hidden))
+	);
+}
+
+#[test]
+fn report_legend() {
+	let error = NeuErr::new("test");
+	let report = error.as_report().location_style(LocationStyle::Hidden).with_legend();
+	assert_eq!(
+		remove_colors(&format!("{report}")),
+		"test\n\n(|- at = source location, caused by: = underlying cause)"
+	);
+	assert_eq!(remove_colors(&format!("{report:#}")), "test");
+}
+
+#[test]
+fn into_single_message() {
+	let message = NeuErr::new("single").attach(0).into_single_message().unwrap();
+	assert_eq!(message, "single");
+
+	let error = NeuErr::new("inner").context("outer").into_single_message().unwrap_err();
+	assert_eq!(error.contexts().count(), 2);
+
+	let error = level0().unwrap_err().into_single_message().unwrap_err();
+	assert!(error.source().is_some());
+
+	assert!(NeuErr::default().attach(0).into_single_message().is_err());
+}
+
+#[test]
+fn fields() {
+	let id = 5;
+	let error = NeuErr::new("test")
+		.with_fields(fields! { user_id = id, action = "login" })
+		.with_fields(fields! { id });
+	let fields = error.fields().collect::<Vec<_>>();
+	assert_eq!(fields, [("id", "5"), ("user_id", "5"), ("action", "login")]);
+	assert_eq!(error.attachment::<Fields>().and_then(|fields| fields.get("id")), Some("5"));
+
+	let pretty =
+		remove_colors(&format!("{}", error.as_report().location_style(LocationStyle::Hidden)));
+	assert_eq!(pretty, "test\n|\n|- fields: id=5, user_id=5, action=login");
+	let compact =
+		remove_colors(&format!("{:#}", error.as_report().location_style(LocationStyle::Hidden)));
+	assert_eq!(compact, "test; fields: id=5, user_id=5, action=login");
+}
+
+/// Tests returning `Result<(), NeuErr>` print the error via `Debug`, which must be the pretty
+/// format.
+#[test]
+fn test_harness_output() {
+	let error = level2().unwrap_err().attach(0);
+	assert_eq!(format!("Error: {error:?}"), format!("Error: {error}"));
+	assert!(!format!("{error:?}").contains("infos"));
+}
+
+#[test]
+fn rendered_size() {
+	let error = level2().unwrap_err();
+	for compact in [false, true] {
+		let rendered =
+			remove_colors(&if compact { format!("{error:#}") } else { format!("{error}") });
+		let lines = rendered.lines().count();
+		let max_width = rendered.lines().map(|line| line.chars().count()).max().unwrap();
+		assert_eq!(error.rendered_size(compact), (lines, max_width));
+	}
+	assert_eq!(error.rendered_size(true).0, 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn write_to() {
+	let error = level2().unwrap_err();
+	for compact in [false, true] {
+		let mut buffer = Vec::new();
+		error.write_to(&mut buffer, compact).unwrap();
+		let expected = if compact { format!("{error:#}") } else { format!("{error}") };
+		assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+	}
+
+	let mut full = [0_u8; 8];
+	let result = error.write_to(&mut &mut full[..], false);
+	assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::WriteZero);
+
+	let report = error.as_report().location_style(LocationStyle::Hidden).max_contexts(1);
+	let mut buffer = Vec::new();
+	report.write_to(&mut buffer).unwrap();
+	assert_eq!(String::from_utf8(buffer).unwrap(), format!("{report}"));
+}
+
+#[test]
+fn attach_display() {
+	let error = NeuErr::new("test").attach_display("multi\nline").attach_display(5);
+	assert_eq!(error.attachment::<&str>(), Some(&"multi\nline"));
+
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(remove_colors(&format!("{report}")), "test\n|\n|- 5\n|\n|- multi\n|  line");
+	assert_eq!(remove_colors(&format!("{report:#}")), "test; 5; multi\nline");
+}
+
+#[test]
+fn log_line() {
+	let error = NeuErr::new("inner").context("outer");
+	let line = line!() - 1;
+	let expected = format!("outer (at tests.rs:{line}); inner (at tests.rs:{line})");
+	assert_eq!(format!("{}", error.log_line()), expected);
+	assert_eq!(format!("{:#}", error.log_line()), expected);
+	assert_eq!(format!("{}", error.as_report().compact()), format!("{error:#}"));
+	assert_eq!(
+		format!("{}", error.as_report().without_colors()),
+		remove_colors(&format!("{error}"))
+	);
+	assert_eq!(
+		format!("{:<5}|", NeuErr::new("x").log_line().location_style(LocationStyle::Hidden)),
+		"x    |"
+	);
+
+	// Escape sequences in messages are kept, only the crate's own colors are not rendered.
+	let error = NeuErr::new("\x1B[1mbold\x1B[0m");
+	let report = error.as_report().location_style(LocationStyle::Hidden).without_colors();
+	assert_eq!(format!("{report}"), "\x1B[1mbold\x1B[0m");
+	assert_eq!(format!("{report:#}"), "\x1B[1mbold\x1B[0m");
+}
+
+#[test]
+fn sort_attachments() {
+	let error = NeuErr::new("test").attach_display(1_u8).attach_display('c').attach_display(2_u8);
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(remove_colors(&format!("{report:#}")), "test; 2; c; 1");
+	assert_eq!(remove_colors(&format!("{:#}", report.sort_attachments())), "test; c; 2; 1");
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn attach_json() {
+	let error = NeuErr::new("test").attach_json(::serde_json::json!({ "code": 5 }));
+	assert_eq!(error.attachment::<JsonContext>().unwrap().0["code"], 5);
+
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(remove_colors(&format!("{report}")), "test\n|\n|- {\n|    \"code\": 5\n|  }");
+	assert_eq!(remove_colors(&format!("{report:#}")), "test; {\"code\":5}");
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn serialize() {
+	let source = SourceError("wahr".parse::<bool>().unwrap_err());
+	let error = NeuErr::new_with_source("Outer", source)
+		.attach(Some(true))
+		.attach_serialize(404_u16)
+		.attach_json(::serde_json::json!({ "code": 5 }));
+	let line = line!() - 4;
+	let error = without_automatic(error);
+
+	let value = ::serde_json::to_value(&error).unwrap();
+	assert_eq!(
+		value,
+		::serde_json::json!({
+			"contexts": [{ "message": "Outer", "file": file!(), "line": line, "column": 17 }],
+			"source": ["SourceError occurred", "provided string was not `true` or `false`"],
+			"attachments": [
+				{ "type": "neuer_error::attachments::JsonContext", "value": { "code": 5 } },
+				{ "type": "u16", "value": 404 },
+				{ "type": "core::option::Option<bool>", "value": "Some(true)" },
+			],
+		})
+	);
+}
+
 #[test]
 fn error_wrapper() {
 	let error = level1().unwrap_err().into_error();
@@ -107,6 +456,240 @@ fn error_wrapper() {
 	assert!(error.source().is_some());
 }
 
+#[test]
+fn downcast_source() {
+	let error = level1().unwrap_err();
+	assert!(error.downcast_source_ref::<SourceError>().is_some());
+	assert!(error.downcast_source_ref::<core::str::ParseBoolError>().is_none());
+
+	let error = error.downcast_source::<core::str::ParseBoolError>().unwrap_err();
+	let source = error.downcast_source::<SourceError>().unwrap();
+	assert_eq!(source.0, "wahr".parse::<bool>().unwrap_err());
+}
+
+#[test]
+fn with_source() {
+	let error = NeuErr::new("test").with_source(source().unwrap_err());
+	assert!(error.downcast_source_ref::<core::str::ParseBoolError>().is_some());
+	assert_eq!(error.source_chain_len(), 1);
+
+	// An existing source is replaced.
+	let error = error.with_source(SourceError(source().unwrap_err()));
+	assert!(error.downcast_source_ref::<SourceError>().is_some());
+	assert_eq!(error.source_chain_len(), 2);
+	assert_eq!(error.contexts().count(), 1);
+}
+
+#[test]
+fn messages() {
+	let error = level2().unwrap_err();
+	assert_eq!(error.message(), Some("Level 2 error"));
+	assert_eq!(
+		error.messages().collect::<Vec<_>>(),
+		["Level 2 error", "Level 1 error", "Level 0 error"]
+	);
+	assert_eq!(NeuErr::from(source().unwrap_err()).message(), None);
+}
+
+#[test]
+fn locations() {
+	let error = NeuErr::new("inner");
+	let line = line!() - 1;
+	let error = error.context("outer");
+	assert_eq!(error.location().unwrap().line(), line + 2);
+	let lines = error.locations().map(Location::line).collect::<Vec<_>>();
+	assert_eq!(lines, [line + 2, line]);
+	assert_eq!(NeuErr::from(source().unwrap_err()).location(), None);
+}
+
+#[test]
+fn chain() {
+	let error = level2().unwrap_err();
+	let mut chain = error.chain();
+	assert!(chain.next().unwrap().is::<SourceError>());
+	assert!(chain.next().unwrap().is::<core::str::ParseBoolError>());
+	assert!(chain.next().is_none());
+
+	assert_eq!(NeuErr::new("test").chain().count(), 0);
+}
+
+#[test]
+fn chain_rev() {
+	let error = level2().unwrap_err();
+	let mut chain = error.chain_rev().map(ToString::to_string);
+	assert_eq!(chain.next().as_deref(), Some("provided string was not `true` or `false`"));
+	assert_eq!(chain.next().as_deref(), Some("SourceError occurred"));
+	assert_eq!(chain.next(), None);
+
+	assert_eq!(NeuErr::new("test").chain_rev().count(), 0);
+}
+
+#[test]
+fn chain_messages() {
+	let error = level2().unwrap_err();
+	assert_eq!(
+		error.chain_messages(),
+		[
+			"Level 2 error",
+			"Level 1 error",
+			"Level 0 error",
+			"SourceError occurred",
+			"provided string was not `true` or `false`",
+		]
+	);
+
+	let nested = NeuErr::new_with_source("Outer", level1().unwrap_err().into_error());
+	let messages = nested.as_report().location_style(LocationStyle::FileLine).chain_messages();
+	assert_eq!(messages.len(), 5);
+	assert!(messages[0].starts_with("Outer (at tests.rs:"), "Found: {messages:?}");
+	assert!(messages[2].starts_with("Level 0 error (at tests.rs:"), "Found: {messages:?}");
+	assert_eq!(messages[4], "provided string was not `true` or `false`");
+}
+
+#[test]
+fn root_cause() {
+	let error = level2().unwrap_err();
+	assert!(error.root_cause().unwrap().is::<core::str::ParseBoolError>());
+	let error = NeuErr::from_source(SourceError("wahr".parse::<bool>().unwrap_err()));
+	assert!(error.root_cause().unwrap().is::<core::str::ParseBoolError>());
+	assert!(NeuErr::new("test").root_cause().is_none());
+}
+
+#[test]
+fn collect_errors() {
+	let error: NeuErr = Vec::<NeuErr>::new().into_iter().collect();
+	assert_eq!(remove_colors(&format!("{error:#}")), "Unknown error");
+
+	let error: NeuErr = ::core::iter::once(NeuErr::new("single")).collect();
+	assert_eq!(error.contexts().next().unwrap().message, "single");
+	assert!(error.source().is_none());
+
+	let error: NeuErr = [NeuErr::new("first"), NeuErr::new("second")].into_iter().collect();
+	let multiple = error.downcast_source_ref::<MultipleErrors>().unwrap();
+	assert_eq!(multiple.len(), 2);
+}
+
+#[test]
+fn unknown_error() {
+	let error = NeuErr::default();
+	assert_eq!(remove_colors(&format!("{error:#}")), "Unknown error");
+	let report = error.as_report().unknown_message("Unbekannter Fehler");
+	assert_eq!(remove_colors(&format!("{report:#}")), "Unbekannter Fehler");
+
+	let error = NeuErr::from_source(SourceError(source().unwrap_err())).attach_display("shown");
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(
+		remove_colors(&format!("{report}")),
+		"SourceError occurred\n|\n|- caused by: provided string was not `true` or `false`\n|\n|- \
+		 shown"
+	);
+	assert_eq!(
+		remove_colors(&format!("{report:#}")),
+		"SourceError occurred; caused by: provided string was not `true` or `false`; shown"
+	);
+}
+
+#[test]
+fn nested_format() {
+	let inner = NeuErr::new_with_source("Inner", source().unwrap_err()).context("Inner context");
+	let error = NeuErr::new_with_source("Outer", inner.into_error());
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+
+	assert_eq!(
+		remove_colors(&format!("{report}")),
+		"Outer\n|\n|- caused by: Inner context\n|  |\n|  Inner\n|  |\n|  |- caused by: provided \
+		 string was not `true` or `false`"
+	);
+	assert_eq!(
+		remove_colors(&format!("{report:#}")),
+		"Outer; caused by: Inner context; Inner; caused by: provided string was not `true` or \
+		 `false`"
+	);
+
+	// Sources of nested errors count towards the maximum source depth.
+	assert_eq!(
+		remove_colors(&format!("{:#}", report.max_source_depth(1))),
+		"Outer; caused by: Inner context; Inner; ... (1 more)"
+	);
+}
+
+#[test]
+fn from_errors() {
+	let errors = ["1", "nein"].into_iter().filter_map(|s| s.parse::<bool>().err());
+	let error = NeuErr::from_errors("Parsing failed", errors);
+	let multiple = error.chain_rev().last().unwrap().downcast_ref::<MultipleErrors>().unwrap();
+	assert_eq!(multiple.len(), 2);
+
+	let compact =
+		remove_colors(&format!("{:#}", error.as_report().location_style(LocationStyle::Hidden)));
+	assert_eq!(
+		compact,
+		"Parsing failed; caused by: 2 errors occurred; error 1: provided string was not `true` or \
+		 `false`; error 2: provided string was not `true` or `false`"
+	);
+	let pretty =
+		remove_colors(&format!("{}", error.as_report().location_style(LocationStyle::Hidden)));
+	assert!(pretty.contains("|  |- error 2: provided string was not `true` or `false`"));
+
+	let nested = NeuErr::from_errors("Outer", [SourceError(source().unwrap_err())]);
+	let pretty =
+		remove_colors(&format!("{}", nested.as_report().location_style(LocationStyle::Hidden)));
+	assert!(pretty.ends_with(
+		"|  |- error 1: SourceError occurred\n|  |  |\n|  |  |- caused by: provided string was not \
+		 `true` or `false`"
+	));
+	assert_eq!(
+		remove_colors(&format!("{:#}", nested.as_report().location_style(LocationStyle::Hidden))),
+		"Outer; caused by: 1 errors occurred; error 1: SourceError occurred; caused by: provided \
+		 string was not `true` or `false`"
+	);
+
+	let errors = [NeuErr::new("First"), NeuErr::new_with_source("Second", source().unwrap_err())];
+	let error = NeuErr::from_errors("Validation failed", errors);
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(
+		remove_colors(&format!("{report}")),
+		"Validation failed\n|\n|- caused by: 2 errors occurred\n|  |\n|  |- error 1: First\n|  \
+		 |\n|  |- error 2: Second\n|  |  |\n|  |  |- caused by: provided string was not `true` or \
+		 `false`"
+	);
+	assert_eq!(
+		remove_colors(&format!("{report:#}")),
+		"Validation failed; caused by: 2 errors occurred; error 1: First; error 2: Second; caused \
+		 by: provided string was not `true` or `false`"
+	);
+}
+
+#[test]
+fn compact_padding() {
+	let error = NeuErr::new("short");
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(remove_colors(&format!("{report:<#8}|")), "short   |");
+	assert_eq!(remove_colors(&format!("{report:*>#8}|")), "***short|");
+	assert_eq!(remove_colors(&format!("{report:^#9}|")), "  short  |");
+	assert_eq!(remove_colors(&format!("{report:#3}|")), "short|");
+}
+
+/// The fast path for single-message errors must render the same as the general path.
+#[test]
+fn single_context_format() {
+	let simple = NeuErr::new("Something went wrong");
+	let attached = NeuErr::new("Something went wrong").attach(0_u8);
+
+	let simple = remove_colors(remove_backtrace(&format!("{simple}")));
+	let attached = remove_colors(remove_backtrace(&format!("{attached}")));
+	let regex = Regex::new(r"^Something went wrong\n\|- at src/tests.rs:\d+:\d+$").unwrap();
+	assert!(regex.is_match(&simple), "{simple}");
+	assert!(regex.is_match(&attached), "{attached}");
+}
+
+#[test]
+fn source_chain_len() {
+	assert_eq!(level2().unwrap_err().source_chain_len(), 2);
+	assert_eq!(NeuErr::from(source().unwrap_err()).source_chain_len(), 1);
+	assert_eq!(NeuErr::new("test").context("more").source_chain_len(), 0);
+}
+
 /// Make sure all the usual types work as context messages.
 #[test]
 fn context() {
@@ -118,6 +701,57 @@ fn context() {
 	assert_eq!(numbers.next(), None);
 }
 
+#[test]
+fn context_with_err() {
+	let result = level1().attach(404_u16).context_with_err(|err| {
+		format!(
+			"Request failed with {}: {}",
+			err.attachment::<u16>().unwrap(),
+			err.message().unwrap()
+		)
+	});
+	let error = result.unwrap_err();
+	assert_eq!(error.message(), Some("Request failed with 404: Level 1 error"));
+	assert!(error.locations().next().unwrap().file().ends_with("tests.rs"));
+}
+
+#[test]
+fn context_try_with() {
+	let error = NeuErr::new("Lookup failed")
+		.context_try_with(|| None::<&str>)
+		.context_try_with(|| Some("User alice"));
+	let mut contexts = error.contexts().map(|ctx| ctx.message.as_ref());
+	assert_eq!(contexts.next(), Some("User alice"));
+	assert_eq!(contexts.next(), Some("Lookup failed"));
+	assert_eq!(contexts.next(), None);
+
+	let result: Result<()> =
+		level0().context_try_with(|| "42".parse::<u8>().ok().map(|n| format!("ID {n}")));
+	assert_eq!(result.unwrap_err().contexts().next().unwrap().message, "ID 42");
+}
+
+#[test]
+fn context_step() {
+	let result: Result<()> = level0().context_step(2, 3, "Migrate data");
+	let error = source().context_step(1, 3, "Read config").unwrap_err();
+	assert_eq!(result.unwrap_err().contexts().next().unwrap().message, "step 2/3: Migrate data");
+	assert_eq!(error.contexts().next().unwrap().message, "step 1/3: Read config");
+}
+
+#[test]
+fn inspect_context() {
+	let mut inspected = Vec::new();
+	let result = level0()
+		.context("Outer")
+		.inspect_context(|err| inspected.push(err.message().unwrap().to_owned()));
+	assert!(result.is_err());
+	let result =
+		source().inspect_context(|err| inspected.push(err.chain().next().unwrap().to_string()));
+	assert!(result.unwrap_err().message().is_none());
+	assert!(Ok(()).inspect_context(|_| inspected.push("ok".to_owned())).is_ok());
+	assert_eq!(inspected, ["Outer", "provided string was not `true` or `false`"]);
+}
+
 #[test]
 fn context_correct_locations() {
 	const START: u32 = line!();
@@ -140,6 +774,9 @@ fn context_correct_locations() {
 	let result: Result<bool> = source().context_with(|_| "test");
 	result.unwrap_err().contexts().map(|ctx| ctx.location).for_each(ensure_location);
 
+	let result: Result<bool> = source().context_step(1, 2, "test").context_step(2, 2, "test");
+	result.unwrap_err().contexts().map(|ctx| ctx.location).for_each(ensure_location);
+
 	#[expect(clippy::items_after_statements, reason = "We need the line number of the end")]
 	const END: u32 = line!();
 }
@@ -154,6 +791,67 @@ fn exit_code() {
 
 	let error = NeuErr::new("test").attach(ExitCode::SUCCESS);
 	assert_eq!(Termination::report(error), ExitCode::SUCCESS);
+
+	let error = NeuErr::new("test").attach(Severity::Warning);
+	assert_eq!(error.severity(), Severity::Warning);
+	assert_eq!(Termination::report(error), ExitCode::SUCCESS);
+
+	let error = NeuErr::new("test").attach(Severity::Fatal).attach(ExitCode::FAILURE);
+	assert_eq!(Termination::report(error), ExitCode::FAILURE);
+	assert_eq!(Termination::report(NeuErr::new("test").attach(Severity::Fatal)), ExitCode::from(2));
+}
+
+#[test]
+fn return_code() {
+	assert_eq!(NeuErr::new("test").into_return_code(), 1);
+	assert_eq!(NeuErr::new("test").attach(Severity::Fatal).into_return_code(), 2);
+	assert_eq!(NeuErr::new("test").attach(ReturnCode(5)).into_return_code(), 5);
+	assert_eq!(
+		NeuErr::new("test").attach(Severity::Warning).attach(ReturnCode(3)).into_return_code(),
+		3
+	);
+	assert_eq!(crate::Ok(()).into_return_code(), 0);
+	assert_eq!(level1().into_return_code(), 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn io_error_conversion() {
+	use std::io::{Error as IoError, ErrorKind};
+
+	let error = NeuErr::new("test");
+	assert_eq!(error.io_error_kind(), None);
+	let error = NeuErr::new_with_source("test", IoError::from(ErrorKind::NotFound));
+	assert_eq!(error.io_error_kind(), Some(ErrorKind::NotFound));
+	let error = error.attach(ErrorKind::PermissionDenied);
+	assert_eq!(error.io_error_kind(), Some(ErrorKind::PermissionDenied));
+
+	#[cfg(all(feature = "send", feature = "sync"))]
+	{
+		let io = IoError::from(error);
+		assert_eq!(io.kind(), ErrorKind::PermissionDenied);
+		assert!(io.get_ref().unwrap().is::<NeuErrImpl>());
+		assert_eq!(IoError::from(NeuErr::new("test")).kind(), ErrorKind::Other);
+	}
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn catch_unwind() {
+	assert_eq!(NeuErr::catch_unwind(|| 5).unwrap(), 5);
+
+	let error = NeuErr::catch_unwind(|| panic!("static message")).unwrap_err();
+	assert_eq!(error.message(), Some("static message"));
+	assert_eq!(error.location().unwrap().line(), line!() - 2);
+	assert!(error.attachment::<Panicked>().is_some());
+
+	let code = 3;
+	let error = NeuErr::catch_unwind(|| panic!("formatted {code}")).unwrap_err();
+	assert_eq!(error.message(), Some("formatted 3"));
+
+	let error = NeuErr::catch_unwind(|| std::panic::panic_any(42_u8)).unwrap_err();
+	assert_eq!(error.message(), Some("Panicked with non-string payload"));
+	assert!(error.attachment::<Panicked>().is_some());
 }
 
 #[test]
@@ -171,12 +869,978 @@ fn attach() {
 	assert_eq!(error.attachments::<bool>().count(), 2);
 }
 
+#[test]
+fn attach_lazy_once() {
+	let mut calls = 0;
+	let mut snapshot = || {
+		calls += 1;
+		calls
+	};
+	let error = NeuErr::new("test").attach_lazy_once(&mut snapshot).attach_lazy_once(&mut snapshot);
+	assert_eq!(error.attachments::<i32>().collect::<Vec<_>>(), [&1]);
+	assert_eq!(calls, 1);
+}
+
+#[test]
+fn attach_all() {
+	let error = NeuErr::new("test").attach(0_u8).attach_all([1_u8, 2, 3]);
+	assert_eq!(error.attachments::<u8>().copied().collect::<Vec<_>>(), [3, 2, 1, 0]);
+
+	let error = error.attach_many((4_u8, 'c', "str"));
+	assert_eq!(error.attachment::<u8>(), Some(&4));
+	assert_eq!(error.attachment::<char>(), Some(&'c'));
+	assert_eq!(error.attachment::<&str>(), Some(&"str"));
+}
+
+#[test]
+fn try_clone() {
+	let error = NeuErr::new_with_cloneable_source("test", source().unwrap_err())
+		.attach_cloneable(5_u8)
+		.with_fields(fields! { id = 1 })
+		.context("more");
+	let cloned = error.try_clone().unwrap();
+	assert_eq!(remove_backtrace(&format!("{cloned}")), remove_backtrace(&format!("{error}")));
+	assert_eq!(cloned.attachment::<u8>(), Some(&5));
+	assert_eq!(cloned.fields().collect::<Vec<_>>(), [("id", "1")]);
+	assert!(cloned.source().is_some());
+
+	assert!(error.attach('c').try_clone().is_none());
+	assert!(NeuErr::from(source().unwrap_err()).try_clone().is_none());
+	assert!(NeuErr::from_cloneable_source(source().unwrap_err()).try_clone().is_some());
+}
+
+#[test]
+fn attachment_mut() {
+	let mut error = NeuErr::new("test").attach(Vec::from(["first"])).attach(1_u8).attach(2_u8);
+	error.attachment_mut::<Vec<&str>>().unwrap().push("second");
+	assert_eq!(error.attachment::<Vec<&str>>().unwrap(), &["first", "second"]);
+
+	*error.attachment_mut::<u8>().unwrap() += 10;
+	error.attachments_mut::<u8>().for_each(|value| *value *= 2);
+	assert_eq!(error.attachments::<u8>().copied().collect::<Vec<_>>(), [24, 2]);
+	assert!(error.attachment_mut::<i32>().is_none());
+}
+
+#[test]
+fn remove_attachment() {
+	let error = NeuErr::new("test").attach(1_u8).attach('c').attach(2_u8);
+	let (error, taken) = error.take_attachment::<u8>();
+	assert_eq!(taken, Some(2));
+	assert_eq!(error.attachments::<u8>().collect::<Vec<_>>(), [&1]);
+
+	let error = error.attach(3_u8).remove_attachment::<u8>();
+	assert_eq!(error.attachment::<u8>(), None);
+	assert_eq!(error.attachment::<char>(), Some(&'c'));
+	assert_eq!(error.take_attachment::<u8>().1, None);
+}
+
+#[test]
+fn into_attachment() {
+	let error = NeuErr::new("test").attach(Vec::from([1_u8, 2])).attach('c');
+	let error = error.into_attachment::<u8>().unwrap_err();
+	assert_eq!(error.into_attachment::<Vec<u8>>().unwrap(), [1, 2]);
+}
+
+#[test]
+fn attachment_in_chain() {
+	let innermost = level0().unwrap_err().attach(1_u8).attach('c');
+	let inner = NeuErr::new_with_source("inner", innermost.into_error()).attach(2_u8);
+	let error = NeuErr::new_with_source("outer", inner.into_error()).attach(true);
+	assert_eq!(error.attachment::<u8>(), None);
+	assert_eq!(error.attachment_in_chain::<bool>(), Some(&true));
+	assert_eq!(error.attachment_in_chain::<u8>(), Some(&2));
+	assert_eq!(error.attachment_in_chain::<char>(), Some(&'c'));
+	assert_eq!(error.attachment_in_chain::<i32>(), None);
+	assert_eq!(error.attachments_in_chain::<u8>().copied().collect::<Vec<_>>(), [2, 1]);
+}
+
+#[test]
+fn attachment_to_string() {
+	let error = NeuErr::new("test").attach(1_u8).attach(2_u8);
+	assert_eq!(error.attachment_to_string::<u8>().as_deref(), Some("2"));
+	assert_eq!(error.attachment_to_string::<i8>(), None);
+}
+
+#[test]
+fn attachments_first() {
+	let error = NeuErr::new("test").attach(1_u8).attach('c').attach(2_u8).attach(3_u8);
+	assert_eq!(error.attachments_first::<u8>(2).collect::<Vec<_>>(), [&3, &2]);
+	assert_eq!(error.attachments_first::<u8>(5).count(), 3);
+	assert_eq!(error.attachments_first::<u8>(0).count(), 0);
+}
+
+#[test]
+fn dedup_attachments() {
+	let mut error = NeuErr::new("test")
+		.attach(1_u8)
+		.attach(1_i8)
+		.attach(2_u8)
+		.context("wrapped")
+		.attach(1_u8)
+		.attach_display(1_i8);
+	error.dedup_attachments();
+	assert_eq!(error.attachments::<u8>().collect::<Vec<_>>(), [&1, &2]);
+	assert_eq!(error.attachments::<i8>().count(), 1);
+	assert_eq!(error.contexts().count(), 2);
+	// The newest duplicate is kept, so the displayed attachment remains.
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(remove_colors(&format!("{report:#}")), "wrapped; test; 1");
+}
+
+#[test]
+fn context_inner() {
+	let error = level2().unwrap_err().context_inner("Details").context("Outer");
+	assert_eq!(
+		error.messages().collect::<Vec<_>>(),
+		["Outer", "Level 2 error", "Level 1 error", "Level 0 error", "Details"]
+	);
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert!(remove_colors(&format!("{report:#}")).starts_with(
+		"Outer; Level 2 error; Level 1 error; Level 0 error; Details; caused by: SourceError"
+	));
+}
+
+#[test]
+fn map_contexts() {
+	let error = level1().unwrap_err().attach_display("token=abc").context("Failed with token=abc");
+	let locations = error.locations().collect::<Vec<_>>();
+	let mut seen = Vec::new();
+	let error = error.map_contexts(|message| {
+		seen.push(message.to_string());
+		*message = message.replace("token=abc", "token=***").into();
+	});
+	assert_eq!(seen, ["Failed with token=abc", "Level 1 error", "Level 0 error"]);
+	assert_eq!(error.message(), Some("Failed with token=***"));
+	assert_eq!(error.locations().collect::<Vec<_>>(), locations);
+	assert_eq!(error.attachment::<&str>(), Some(&"token=abc"));
+}
+
+#[test]
+fn redact() {
+	#[derive(Debug)]
+	struct Token(&'static str);
+	impl Sensitive for Token {}
+
+	#[derive(Debug)]
+	struct Password;
+	impl Sensitive for Password {}
+
+	let error = NeuErr::new("test")
+		.attach_sensitive(Token("secret"))
+		.attach(Token("unmarked"))
+		.attach_sensitive(Password)
+		.attach(5_u8)
+		.redact_sensitive();
+	assert_eq!(error.attachments::<Token>().map(|token| token.0).collect::<Vec<_>>(), ["unmarked"]);
+	assert!(error.attachment::<Password>().is_none());
+	assert_eq!(error.attachment::<u8>(), Some(&5));
+
+	let error = error.redact::<Token>();
+	assert!(error.attachment::<Token>().is_none());
+	assert_eq!(error.attachment::<u8>(), Some(&5));
+}
+
+#[test]
+fn into_attachments() {
+	let error = without_automatic(level1().unwrap_err().attach(1_u8).attach("text"));
+	let attachments = error.into_attachments().map(|attachment| format!("{attachment:?}"));
+	assert_eq!(attachments.collect::<Vec<_>>(), ["\"text\"", "1"]);
+
+	let error = level1().unwrap_err().attach(1_u8).context("Outer");
+	let contexts = error.into_contexts().collect::<Vec<_>>();
+	assert_eq!(
+		contexts.iter().map(|(message, _)| message.as_ref()).collect::<Vec<_>>(),
+		["Outer", "Level 1 error", "Level 0 error"]
+	);
+	assert!(contexts.iter().all(|(_, location)| location.file().ends_with("tests.rs")));
+}
+
+#[test]
+fn introspection() {
+	let error = NeuErr::default();
+	assert!(error.is_empty());
+	assert!(!error.has_source());
+	assert_eq!(error.context_count(), 0);
+	assert_eq!(error.attachment_count(), 0);
+
+	let error = NeuErr::from_source(SourceError("wahr".parse::<bool>().unwrap_err()));
+	assert!(!error.is_empty());
+	assert!(error.has_source());
+
+	let error = without_automatic(level1().unwrap_err().attach(1_u8).attach_display('c'));
+	assert!(!error.is_empty());
+	assert_eq!(error.context_count(), 2);
+	assert_eq!(error.attachment_count(), 2);
+}
+
+#[test]
+fn dedup_contexts() {
+	let mut error = NeuErr::new("test");
+	for attempt in 0 .. 3_u8 {
+		error = error.context("retrying").attach(attempt);
+	}
+	let error = error.context("failed").context("failed").context("retrying").dedup_contexts();
+	assert_eq!(error.attachments::<u8>().count(), 3);
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(remove_colors(&format!("{report:#}")), "retrying; failed; failed; retrying; test");
+}
+
+#[test]
+fn attach_for() {
+	#[derive(Debug, PartialEq)]
+	enum Phase {
+		Connect,
+		Handshake,
+	}
+
+	let error = NeuErr::new("test")
+		.attach_for(Phase::Connect, 1_u8)
+		.attach_for(Phase::Handshake, 2_u8)
+		.attach_for(Phase::Connect, "connect");
+	assert_eq!(error.attachment_for::<_, u8>(&Phase::Connect), Some(&1));
+	assert_eq!(error.attachment_for::<_, u8>(&Phase::Handshake), Some(&2));
+	assert_eq!(error.attachment_for::<_, &str>(&Phase::Connect), Some(&"connect"));
+	assert_eq!(error.attachment_for::<_, &str>(&Phase::Handshake), None);
+	assert_eq!(error.attachment::<u8>(), None);
+}
+
+#[test]
+fn swap_attachment() {
+	let mut error = NeuErr::new("test").attach(1_u8).attach('c').attach(2_u8);
+	assert_eq!(error.swap_attachment(3_u8), Some(2));
+	assert_eq!(error.swap_attachment(true), None);
+	assert_eq!(error.attachments::<u8>().collect::<Vec<_>>(), [&3, &1]);
+	assert_eq!(error.attachment::<bool>(), Some(&true));
+}
+
+#[test]
+fn drain_attachments() {
+	let mut error = NeuErr::new("test").attach(1_u8).attach('c').attach(2_u8).attach(3_u8);
+	assert_eq!(error.drain_attachments::<u8>().next(), Some(1));
+	assert_eq!(error.attachments::<u8>().collect::<Vec<_>>(), [&3, &2]);
+	assert_eq!(error.drain_attachments::<u8>().collect::<Vec<_>>(), [2, 3]);
+	assert_eq!(error.attachment::<u8>(), None);
+	assert_eq!(error.attachment::<char>(), Some(&'c'));
+	assert_eq!(error.contexts().count(), 1);
+}
+
+#[test]
+fn msg_conversion() {
+	fn adhoc() -> Result<u8> {
+		let value = None.ok_or(Msg("static message"))?;
+		Ok(value)
+	}
+
+	let error = adhoc().unwrap_err();
+	assert_eq!(error.message(), Some("static message"));
+	assert_eq!(error.location().unwrap().line(), line!() - 6);
+	assert!(error.location().unwrap().file().ends_with("tests.rs"));
+
+	let error = NeuErr::from(Msg(String::from("owned message")));
+	assert_eq!(error.message(), Some("owned message"));
+	assert_eq!(error.location().unwrap().line(), line!() - 2);
+
+	let error: NeuErr = Msg(Cow::Borrowed("cow message")).into();
+	assert_eq!(error.message(), Some("cow message"));
+	assert_eq!(error.location().unwrap().line(), line!() - 2);
+}
+
+#[test]
+fn tracked_source() {
+	fn tracked() -> Result<bool> {
+		let value = source().map_err(Tracked)?;
+		Ok(value)
+	}
+
+	let error = tracked().unwrap_err();
+	let line = line!() - 5;
+	let pretty = remove_colors(remove_backtrace(&format!("{error}")));
+	let matcher = Regex::new(&format!(
+		r"^provided string was not `true` or `false`\n\|- at src/tests\.rs:{line}:\d+$"
+	))
+	.unwrap();
+	assert!(matcher.is_match(&pretty), "Found: {pretty}");
+	let compact = remove_colors(&format!("{error:#}"));
+	let matcher = Regex::new(&format!(
+		r"^provided string was not `true` or `false` \(at src/tests\.rs:{line}:\d+\)$"
+	))
+	.unwrap();
+	assert!(matcher.is_match(&compact), "Found: {compact}");
+
+	let untracked = NeuErr::from_source(source().unwrap_err());
+	assert!(!format!("{untracked}").contains("|- at"));
+}
+
+#[cfg(feature = "backtrace")]
+#[test]
+fn backtrace() {
+	use ::std::backtrace::{Backtrace, BacktraceStatus};
+
+	let enabled = Backtrace::capture().status() == BacktraceStatus::Captured;
+	for error in [
+		NeuErr::new("test"),
+		level0().unwrap_err(),
+		NeuErr::from(SourceError(source().unwrap_err())),
+	] {
+		assert_eq!(error.backtrace().is_some(), enabled);
+		assert_eq!(format!("{error}").contains("|- backtrace:\n|  "), enabled);
+		assert!(!format!("{error:#}").contains("backtrace"));
+	}
+}
+
+#[test]
+fn from_location() {
+	fn converted() -> Result<bool> {
+		let value = source()?;
+		Ok(value)
+	}
+
+	let error = converted().unwrap_err();
+	let line = line!() - 5;
+	let pretty = remove_colors(remove_backtrace(&format!("{error}")));
+	let matcher = Regex::new(&format!(
+		r"^provided string was not `true` or `false`\n\|- at src/tests\.rs:{line}:\d+$"
+	))
+	.unwrap();
+	assert!(matcher.is_match(&pretty), "Found: {pretty}");
+	assert_eq!(error.source_location().map(Location::line), Some(line));
+	assert_eq!(NeuErr::from_source(source().unwrap_err()).source_location(), None);
+}
+
+#[cfg(feature = "test-utils")]
+#[test]
+fn eq_messages() {
+	let error = level1().unwrap_err();
+	assert!(error.eq_messages(&NeuErr::new("Level 0 error").context("Level 1 error")));
+	assert!(!error.eq_messages(&NeuErr::new("Level 1 error")));
+
+	assert_eq!(error, level1().unwrap_err());
+	assert_ne!(error, NeuErr::new("Level 0 error").context("Level 1 error"));
+}
+
+#[cfg(feature = "test-utils")]
+#[test]
+fn has_attachment() {
+	#[derive(Debug, PartialEq)]
+	enum Retryable {
+		Yes,
+		No,
+	}
+
+	let error = level1().unwrap_err().attach(Retryable::Yes);
+	assert!(error.has_attachment::<Retryable>());
+	assert!(error.has_attachment_eq(&Retryable::Yes));
+	assert!(!error.has_attachment_eq(&Retryable::No));
+	assert!(!error.has_attachment::<u8>());
+}
+
+#[cfg(feature = "test-utils")]
+#[test]
+fn diff() {
+	let error = level1().unwrap_err().attach(1_u8);
+	assert_eq!(error.diff(&level1().unwrap_err().attach(1_u8)), "");
+
+	let other = NeuErr::new("Level 0 error").context("Other error").attach(2_u8).attach(true);
+	assert_eq!(
+		error.diff(&other),
+		"context 0: \"Level 1 error\" != \"Other error\"\nattachment 0: u8(1) != bool(true)\n\
+		 attachment 1: <missing> != u8(2)\nsource 0: SourceError occurred != <missing>\nsource 1: \
+		 provided string was not `true` or `false` != <missing>\n"
+	);
+}
+
+#[test]
+fn code_from_source() {
+	fn code(error: &(dyn Error + 'static)) -> Cow<'static, str> {
+		match error.downcast_ref::<SourceError>() {
+			Some(_) => "E0001".into(),
+			None => "E9999".into(),
+		}
+	}
+
+	let error = level0().unwrap_err().code_from_source(code);
+	assert_eq!(error.attachment::<ErrorCode>(), Some(&ErrorCode("E0001".into())));
+	let error = NeuErr::from(source().unwrap_err()).code_from_source(code);
+	assert_eq!(error.attachment::<ErrorCode>(), Some(&ErrorCode("E9999".into())));
+	let error = NeuErr::new("test").code_from_source(code);
+	assert_eq!(error.attachment::<ErrorCode>(), None);
+}
+
+#[test]
+fn from_boxed() {
+	fn library() -> core::result::Result<(), Box<dyn Error + Send + Sync>> {
+		Err(Box::new(SourceError(source().unwrap_err())))
+	}
+
+	let error = library().map_err(NeuErr::from_boxed).context("Library failed").unwrap_err();
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(
+		remove_colors(&format!("{report:#}")),
+		"Library failed; caused by: SourceError occurred; caused by: provided string was not \
+		 `true` or `false`"
+	);
+	assert!(error.source().unwrap().source().is_some());
+}
+
+#[test]
+fn hints() {
+	let error = NeuErr::new("Build failed")
+		.note("the cache was cleared")
+		.help("try running with --verbose")
+		.attach_display("shown");
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(
+		remove_colors(remove_backtrace(&format!("{report}"))),
+		"Build failed\n|\n|- shown\n\n= note: the cache was cleared\n= help: try running with \
+		 --verbose"
+	);
+	assert_eq!(
+		remove_colors(&format!("{report:#}")),
+		"Build failed; shown; note: the cache was cleared; help: try running with --verbose"
+	);
+
+	let result: Result<()> = Err(NeuErr::new("Build failed")).help("retry");
+	assert_eq!(result.unwrap_err().attachment::<Help>(), Some(&Help("retry".into())));
+}
+
+#[test]
+fn error_code_format() {
+	let error = NeuErr::new("Token expired").attach(ErrorCode("auth::token_expired".into()));
+	assert_eq!(error.code(), Some("auth::token_expired"));
+	assert_eq!(NeuErr::new("test").code(), None);
+
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(remove_colors(&format!("{report}")), "[auth::token_expired] Token expired");
+	let error = error.context("Login failed").attach(ErrorCode("E1234".into()));
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(remove_colors(&format!("{report:#}")), "[E1234] Login failed; Token expired");
+}
+
+#[test]
+fn and_then_ctx() {
+	let parse = |s: &str| s.parse::<u8>().context("Parsing failed");
+	let value = Result::<&str>::Ok("5").and_then_ctx("Reading number", parse);
+	assert_eq!(value.unwrap(), 5);
+
+	let error = Result::<&str>::Ok("x").and_then_ctx("Reading number", parse).unwrap_err();
+	let mut contexts = error.contexts().map(|ctx| ctx.message.as_ref());
+	assert_eq!(contexts.next(), Some("Reading number"));
+	assert_eq!(contexts.next(), Some("Parsing failed"));
+	assert!(error.source().is_some());
+
+	let error = source().and_then_ctx("Reading flag", |_| Ok(())).unwrap_err();
+	assert_eq!(error.contexts().next().unwrap().message, "Reading flag");
+	assert!(error.source().is_some());
+	assert!(error.source_location().unwrap().file().ends_with("tests.rs"));
+	assert!(error.contexts().next().unwrap().location.file().ends_with("tests.rs"));
+}
+
+#[test]
+fn neuer_macro() {
+	let path = "config.toml";
+	let error = neuer!("Failed to load {path}"; source = source().unwrap_err(); attach: 1_u8, 'c');
+	let line = line!() - 1;
+	let context = error.contexts().next().unwrap();
+	assert_eq!(context.message, "Failed to load config.toml");
+	assert_eq!((context.location.file(), context.location.line()), ("src/tests.rs", line));
+	assert_eq!(error.attachment::<u8>(), Some(&1));
+	assert_eq!(error.attachment::<char>(), Some(&'c'));
+	assert!(error.source().is_some());
+
+	let error = neuer!("Value {} out of {}", 5, 3; attach: 0_i32,);
+	assert_eq!(error.contexts().next().unwrap().message, "Value 5 out of 3");
+	assert_eq!(error.attachment::<i32>(), Some(&0));
+	assert!(error.source().is_none());
+}
+
+#[test]
+fn format_err_macro() {
+	let id = 5;
+	let error = format_err!("User {id} already exists"; attach: id);
+	let context = error.contexts().next().unwrap();
+	assert_eq!(context.message, "User 5 already exists");
+	assert_eq!((context.location.file(), context.location.line()), ("src/tests.rs", line!() - 3));
+	assert_eq!(error.attachment::<i32>(), Some(&5));
+}
+
+#[test]
+fn bail_macro() {
+	fn find_user(id: u32) -> Result<()> {
+		bail!("User {id} not found");
+	}
+
+	let error = find_user(5).unwrap_err();
+	let context = error.contexts().next().unwrap();
+	assert_eq!(context.message, "User 5 not found");
+	assert_eq!((context.location.file(), context.location.line()), ("src/tests.rs", line!() - 6));
+}
+
+#[test]
+fn ensure_macro() {
+	fn check(value: u8) -> Result<()> {
+		ensure!(value < 10, "Value {value} too large");
+		ensure!(value > 0, "Value {} too small", value; attach = value);
+		Ok(())
+	}
+
+	assert!(check(5).is_ok());
+	let error = check(10).unwrap_err();
+	let context = error.contexts().next().unwrap();
+	assert_eq!(context.message, "Value 10 too large");
+	assert_eq!((context.location.file(), context.location.line()), ("src/tests.rs", line!() - 9));
+	assert_eq!(error.attachment::<u8>(), None);
+
+	let error = check(0).unwrap_err();
+	let context = error.contexts().next().unwrap();
+	assert_eq!(context.message, "Value 0 too small");
+	assert_eq!(context.location.line(), line!() - 14);
+	assert_eq!(error.attachment::<u8>(), Some(&0));
+}
+
+#[test]
+fn error_kinds() {
+	mod kinds {
+		crate::error_kinds! {
+			/// Kinds of test errors.
+			pub exhaustive enum Kind {
+				/// Not found.
+				NotFound,
+				Invalid,
+			}
+
+			status(single: u16) -> Option<u16> { |status| status.copied() };
+		}
+	}
+	use kinds::{Kind, NeuErrAttachments as _};
+
+	let error = Kind::NotFound.error("Missing").attach(404_u16);
+	assert_eq!(error.kind(), Some(Kind::NotFound));
+	assert_eq!(error.status(), Some(404));
+	assert_eq!(error.message(), Some("Missing"));
+
+	let error = error.attach_kind(Kind::Invalid);
+	assert_eq!(error.kind(), Some(Kind::Invalid));
+	assert_eq!(error.attachments::<Kind>().count(), 1);
+
+	assert!(Kind::Invalid.ensure(true, "Unreachable").is_ok());
+	let error = Kind::Invalid.ensure(false, format!("Value {} invalid", 5)).unwrap_err();
+	assert_eq!(error.kind(), Some(Kind::Invalid));
+	assert!(error.locations().next().unwrap().file().ends_with("tests.rs"));
+}
+
 #[test]
 fn multi_errors() {
 	let mut errors: Vec<NeuErr> = Vec::new();
 	level1().or_collect(&mut errors);
 	level2().or_collect(&mut errors);
 	assert_eq!(errors.len(), 2);
+
+	assert_eq!(level0().or_collect_count(&mut errors), Err(3));
+	assert_eq!(Ok(5).or_collect_count(&mut errors), ::core::result::Result::Ok(5));
+	assert_eq!(errors.len(), 3);
+}
+
+#[test]
+fn partition_results() {
+	let results = [level0().map(|()| 0), Ok(1), level1().map(|()| 2), Ok(3)];
+	let (values, errors) = crate::partition_results(results);
+	assert_eq!(values, [1, 3]);
+	assert_eq!(
+		errors.iter().map(|err| err.message().unwrap()).collect::<Vec<_>>(),
+		["Level 0 error", "Level 1 error"]
+	);
+
+	let (values, errors) = crate::partition_results(["true", "wahr"].map(str::parse::<bool>));
+	assert_eq!(values, [true]);
+	assert_eq!(errors.len(), 1);
+}
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+#[test]
+fn unwrap_or_default_logged() {
+	let value = level1().map(|()| 5_u8).unwrap_or_default_logged();
+	assert_eq!(value, 0);
+
+	let value = Result::<u8>::Ok(5).unwrap_or_default_logged();
+	assert_eq!(value, 5);
+}
+
+#[cfg(all(feature = "log", feature = "std"))]
+#[test]
+fn log_error() {
+	use ::std::sync::{Mutex, PoisonError};
+
+	/// Captured message and key-values of a log record.
+	type Captured = (String, Vec<(String, String)>);
+
+	/// Logger capturing the records logged by `NeuErr::log`.
+	struct CaptureLogger(Mutex<Vec<Captured>>);
+
+	impl ::log::Log for CaptureLogger {
+		fn enabled(&self, _metadata: &::log::Metadata<'_>) -> bool {
+			true
+		}
+
+		fn log(&self, record: &::log::Record<'_>) {
+			struct Collect(Vec<(String, String)>);
+			impl<'kvs> ::log::kv::VisitSource<'kvs> for Collect {
+				fn visit_pair(
+					&mut self,
+					key: ::log::kv::Key<'kvs>,
+					value: ::log::kv::Value<'kvs>,
+				) -> ::core::result::Result<(), ::log::kv::Error> {
+					self.0.push((key.to_string(), value.to_string()));
+					::core::result::Result::Ok(())
+				}
+			}
+
+			if record.target() != "neuer_error" {
+				return;
+			}
+			let mut collect = Collect(Vec::new());
+			record.key_values().visit(&mut collect).unwrap();
+			let mut records = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+			records.push((record.args().to_string(), collect.0));
+		}
+
+		fn flush(&self) {}
+	}
+
+	static LOGGER: CaptureLogger = CaptureLogger(Mutex::new(Vec::new()));
+	::log::set_logger(&LOGGER).unwrap();
+	::log::set_max_level(::log::LevelFilter::Trace);
+
+	let error = level1()
+		.unwrap_err()
+		.attach_display("displayed")
+		.attach(5_u8)
+		.with_fields(fields!(user_id = 42));
+	error.log(::log::Level::Warn);
+
+	let records = LOGGER.0.lock().unwrap_or_else(PoisonError::into_inner);
+	let (message, key_values) = records.first().unwrap();
+	assert_eq!(message, "Level 1 error");
+	assert_eq!(key_values[0], ("error".to_owned(), format!("{error:#}")));
+	assert_eq!(key_values[1], ("user_id".to_owned(), "42".to_owned()));
+	assert!(key_values.contains(&("&str".to_owned(), "displayed".to_owned())));
+	assert!(key_values.contains(&("u8".to_owned(), "5".to_owned())));
+}
+
+#[cfg(all(feature = "trace-creation", feature = "std"))]
+#[test]
+fn trace_creation() {
+	use ::core::sync::atomic::{AtomicUsize, Ordering};
+	use ::tracing::{Event, Metadata, Subscriber, span};
+
+	/// Subscriber counting the events of created errors.
+	struct CountCreated(AtomicUsize);
+
+	impl Subscriber for &'static CountCreated {
+		fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+			true
+		}
+		fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+			span::Id::from_u64(1)
+		}
+		fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+		fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+		fn event(&self, event: &Event<'_>) {
+			if event.metadata().target() == "neuer_error" {
+				self.0.fetch_add(1, Ordering::Relaxed);
+			}
+		}
+		fn enter(&self, _span: &span::Id) {}
+		fn exit(&self, _span: &span::Id) {}
+	}
+
+	static CREATED: CountCreated = CountCreated(AtomicUsize::new(0));
+	::tracing::subscriber::with_default(&CREATED, || {
+		_ = NeuErr::new("first");
+		_ = NeuErr::new_with_source("second", source().unwrap_err());
+	});
+	assert_eq!(CREATED.0.load(Ordering::Relaxed), 2);
+}
+
+#[cfg(feature = "span-fields")]
+#[test]
+fn span_fields() {
+	use ::tracing_subscriber::layer::SubscriberExt;
+
+	let subscriber = ::tracing_subscriber::registry().with(::tracing_error::ErrorLayer::default());
+	::tracing::subscriber::with_default(subscriber, || {
+		let span = ::tracing::info_span!("request", user_id = 42);
+		let _guard = span.enter();
+		let error = NeuErr::new("failed");
+		assert_eq!(
+			error.span_fields().and_then(|fields| fields.get("request")),
+			Some("user_id=42")
+		);
+
+		let error = NeuErr::from_source(source().unwrap_err());
+		assert!(error.span_fields().is_none());
+		assert!(error.capture_span().span_fields().is_some());
+	});
+	assert!(NeuErr::new("outside").span_fields().is_none());
+}
+
+#[cfg(feature = "anyhow")]
+#[test]
+fn anyhow_conversion() {
+	use ::anyhow::Context as _;
+
+	let result: ::anyhow::Result<bool> =
+		source().map_err(::anyhow::Error::new).context("Anyhow context");
+	let error = result.map_err(NeuErr::from_anyhow).context("Converted").unwrap_err();
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(
+		remove_colors(&format!("{report:#}")),
+		"Converted; caused by: Anyhow context; caused by: provided string was not `true` or `false`"
+	);
+
+	#[cfg(all(feature = "send", feature = "sync"))]
+	{
+		let anyhow = ::anyhow::Error::from(NeuErr::new("Back"));
+		assert!(remove_colors(&anyhow.to_string()).starts_with("Back"));
+		assert!(anyhow.downcast_ref::<NeuErrImpl>().is_some());
+	}
+}
+
+#[cfg(feature = "defmt")]
+#[test]
+fn defmt_format() {
+	// Logging requires a `defmt` logger of an embedded target, so check the emitted pieces.
+	const fn assert_format<T: ::defmt::Format>() {}
+	assert_format::<NeuErr>();
+
+	let error = NeuErr::new("inner").context("outer");
+	let line = line!() - 1;
+	let rendered = crate::defmt::pieces(&error).map(|piece| piece.to_string()).collect::<String>();
+	assert_eq!(
+		rendered,
+		format!("outer (at {file}:{line}); inner (at {file}:{line})", file = file!())
+	);
+
+	let error = level0().unwrap_err();
+	let rendered = crate::defmt::pieces(&error).map(|piece| piece.to_string()).collect::<String>();
+	let matcher = Regex::new(r"^Level 0 error \(at src/tests\.rs:\d+\); caused by: SourceError occurred; caused by: provided string was not `true` or `false`$").expect("failed compiling regex");
+	assert!(matcher.is_match(&rendered), "Found: {rendered}");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derive_attachment() {
+	#[derive(Debug, PartialEq, Attachment)]
+	struct Retryable;
+
+	#[derive(Debug, Clone, PartialEq, Attachment)]
+	#[attachment(name = "request_id", clone)]
+	#[cfg_attr(feature = "serde", attachment(serialize))]
+	struct RequestId(u64);
+
+	#[cfg(feature = "serde")]
+	impl ::serde::Serialize for RequestId {
+		fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+		where
+			S: ::serde::Serializer,
+		{
+			serializer.serialize_u64(self.0)
+		}
+	}
+
+	assert_eq!(Retryable::NAME, "Retryable");
+	assert_eq!(RequestId::NAME, "request_id");
+
+	let error = RequestId(7).attach_to(NeuErr::new("Failed").attach_named(Retryable));
+	assert_eq!(error.attachment::<RequestId>(), Some(&RequestId(7)));
+	// Only `RequestId` opted into cloning.
+	assert!(error.try_clone().is_none());
+	let error = without_automatic(error.remove_attachment::<Retryable>());
+	let cloned = error.try_clone().unwrap();
+	assert_eq!(cloned.attachment::<RequestId>(), Some(&RequestId(7)));
+
+	#[cfg(all(feature = "serde", feature = "serde_json"))]
+	{
+		let error = error.attach_named(Retryable);
+		let value = ::serde_json::to_value(&error).unwrap();
+		assert_eq!(value["attachments"][0]["type"], "Retryable");
+		assert_eq!(value["attachments"][0]["value"], "Retryable");
+		assert_eq!(value["attachments"][1]["type"], "request_id");
+		assert_eq!(value["attachments"][1]["value"], 7);
+	}
+}
+
+#[cfg(feature = "miette")]
+#[test]
+fn miette_diagnostic() {
+	use ::miette::Diagnostic;
+
+	let inner = NeuErr::new("Inner").attach(ErrorCode("E0002".into()));
+	let error = NeuErr::new_with_source("Outer", inner.into_error())
+		.attach(ErrorCode("E0001".into()))
+		.attach(Severity::Warning)
+		.help("first")
+		.help("second")
+		.into_error();
+	assert_eq!(error.code().unwrap().to_string(), "E0001");
+	assert_eq!(error.severity(), Some(::miette::Severity::Warning));
+	assert_eq!(error.help().unwrap().to_string(), "first\nsecond");
+	let source = error.diagnostic_source().unwrap();
+	assert_eq!(source.code().unwrap().to_string(), "E0002");
+	assert!(source.help().is_none());
+}
+
+#[cfg(feature = "axum")]
+#[test]
+fn axum_response() {
+	use ::axum_core::response::IntoResponse;
+	use ::http::StatusCode;
+
+	fn find() -> Result<()> {
+		Err(NeuErr::new("Not found").attach(StatusCode::NOT_FOUND))
+	}
+	fn handler() -> ::core::result::Result<(), NeuErrResponse> {
+		find()?;
+		::core::result::Result::Ok(())
+	}
+
+	let response = handler().into_response();
+	assert_eq!(response.status(), StatusCode::NOT_FOUND);
+	let response = NeuErrResponse(NeuErr::new("test")).into_response();
+	assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[cfg(feature = "timestamp")]
+#[test]
+fn timestamp() {
+	use ::core::time::Duration;
+	use ::std::time::{SystemTime, UNIX_EPOCH};
+
+	let before = SystemTime::now();
+	let error = NeuErr::new("test");
+	assert!(error.created_at().is_some_and(|created_at| created_at >= before));
+	assert!(NeuErr::from_source(source().unwrap_err()).created_at().is_some());
+	assert!(level2().unwrap_err().created_at().is_some());
+
+	let created_at = CreatedAt(UNIX_EPOCH + Duration::from_millis(1_760_531_696_789));
+	assert_eq!(created_at.to_string(), "2025-10-15T12:34:56.789Z");
+	let created_at = CreatedAt(UNIX_EPOCH + Duration::from_secs(951_782_400));
+	assert_eq!(created_at.to_string(), "2000-02-29T00:00:00.000Z");
+
+	let report = error.as_report().location_style(LocationStyle::Hidden);
+	assert_eq!(remove_colors(&format!("{report:#}")), "test");
+	let timestamped = remove_colors(&format!("{:#}", report.with_timestamp()));
+	let matcher = Regex::new(r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}\.\d{3}Z test$")
+		.expect("failed compiling regex");
+	assert!(matcher.is_match(&timestamped), "Found: {timestamped}");
+}
+
+#[cfg(all(feature = "thread-info", feature = "send"))]
+#[test]
+fn thread_info() {
+	let error = NeuErr::new("test");
+	assert_eq!(error.thread(), Some(&ThreadInfo::current()));
+	assert!(level2().unwrap_err().thread().is_some());
+
+	let error = ::std::thread::Builder::new()
+		.name("worker-1".into())
+		.spawn(|| NeuErr::from_source(source().unwrap_err()))
+		.unwrap()
+		.join()
+		.unwrap();
+	let thread = error.thread().unwrap();
+	assert_ne!(thread.id, ::std::thread::current().id());
+	assert_eq!(thread.to_string(), "worker-1");
+}
+
+#[cfg(feature = "sentry")]
+#[test]
+fn sentry_event() {
+	let error = level2().unwrap_err().attach(0_i32);
+	let event = error.to_sentry_event();
+
+	let exceptions = &event.exception.values;
+	assert_eq!(exceptions.len(), 3);
+	assert_eq!(exceptions[0].value.as_deref(), Some("provided string was not `true` or `false`"));
+	assert_eq!(exceptions[1].value.as_deref(), Some("SourceError occurred"));
+	assert_eq!(exceptions[2].ty, "NeuErr");
+	assert_eq!(exceptions[2].value.as_deref(), Some("Level 2 error"));
+
+	let breadcrumbs = &event.breadcrumbs.values;
+	assert_eq!(breadcrumbs.len(), 3);
+	assert_eq!(breadcrumbs[0].message.as_deref(), Some("Level 0 error"));
+	assert_eq!(breadcrumbs[0].data["file"], "src/tests.rs");
+
+	assert_eq!(event.extra["i32"], "0");
+}
+
+#[cfg(feature = "opentelemetry")]
+#[test]
+fn record_otel() {
+	use ::opentelemetry::{
+		KeyValue,
+		trace::{Span, SpanContext, Status},
+	};
+
+	/// Span recording status and events.
+	#[derive(Debug)]
+	struct RecordingSpan {
+		/// Recorded status.
+		status: Option<Status>,
+		/// Recorded events.
+		events: Vec<(String, Vec<KeyValue>)>,
+		/// Invalid span context.
+		context: SpanContext,
+	}
+	impl Span for RecordingSpan {
+		fn add_event_with_timestamp<T>(
+			&mut self,
+			name: T,
+			_timestamp: std::time::SystemTime,
+			attributes: Vec<KeyValue>,
+		) where
+			T: Into<Cow<'static, str>>,
+		{
+			self.events.push((name.into().into_owned(), attributes));
+		}
+		fn span_context(&self) -> &SpanContext {
+			&self.context
+		}
+		fn is_recording(&self) -> bool {
+			true
+		}
+		fn set_attribute(&mut self, _attribute: KeyValue) {}
+		fn set_status(&mut self, status: Status) {
+			self.status = Some(status);
+		}
+		fn update_name<T>(&mut self, _new_name: T)
+		where
+			T: Into<Cow<'static, str>>,
+		{
+		}
+		fn add_link(&mut self, _span_context: SpanContext, _attributes: Vec<KeyValue>) {}
+		fn end_with_timestamp(&mut self, _timestamp: std::time::SystemTime) {}
+	}
+
+	let error = level1().unwrap_err().attach(0_i32).attach(1_i32).with_fields(fields! { id = 5 });
+	let error = without_automatic(error);
+	let mut span =
+		RecordingSpan { status: None, events: Vec::new(), context: SpanContext::empty_context() };
+	error.record_otel(&mut span);
+
+	assert_eq!(span.status, Some(Status::error("Level 1 error")));
+	let (name, attributes) = &span.events[0];
+	assert_eq!(name, "exception");
+	let attribute = |key: &str| {
+		attributes
+			.iter()
+			.find(|attribute| attribute.key.as_str() == key)
+			.map(|attribute| attribute.value.to_string())
+	};
+	assert_eq!(attribute("exception.type").as_deref(), Some("neuer_error::tests::SourceError"));
+	assert_eq!(attribute("exception.message"), Some(format!("{error:#}")));
+	assert_eq!(attribute("id").as_deref(), Some("5"));
+	assert_eq!(attribute("i32").as_deref(), Some("1"));
+	assert_eq!(attributes.len(), 4);
 }
 
 #[cfg(all(not(feature = "send"), not(feature = "sync")))]
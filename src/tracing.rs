@@ -0,0 +1,78 @@
+//! Integration with `tracing` for capturing span fields.
+
+use ::alloc::{string::String, vec::Vec};
+use ::tracing_error::SpanTrace;
+
+use crate::{
+	NeuErr,
+	error::{AttachmentHooks, Info, Infos, MachineInfo},
+};
+
+/// Snapshot of the `tracing` spans active when the error was created, with their recorded
+/// fields. It is captured automatically at error creation via [`NeuErr::new`] and
+/// [`NeuErr::new_with_source`], or manually via [`NeuErr::capture_span`].
+///
+/// Capturing requires the subscriber to record the span fields via the
+/// [`ErrorLayer`](::tracing_error::ErrorLayer) of `tracing-error`, e.g.
+/// `tracing_subscriber::registry().with(ErrorLayer::default())`. Without it, nothing is captured.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct SpanFields(Vec<(&'static str, String)>);
+
+impl SpanFields {
+	/// Capture the fields of the current spans. Returns `None` if there are no spans or the fields
+	/// are not recorded.
+	#[must_use]
+	pub fn capture() -> Option<Self> {
+		let mut spans = Vec::new();
+		SpanTrace::capture().with_spans(|metadata, fields| {
+			spans.push((metadata.name(), fields.into()));
+			true
+		});
+		(!spans.is_empty()).then_some(Self(spans))
+	}
+
+	/// Get an iterator over the span names and their formatted fields, innermost span first.
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+		self.0.iter().map(|(name, fields)| (*name, fields.as_str()))
+	}
+
+	/// Get the formatted fields of the innermost span with the given name.
+	#[must_use]
+	#[inline]
+	pub fn get(&self, span: &str) -> Option<&str> {
+		self.iter().find_map(|(name, fields)| (name == span).then_some(fields))
+	}
+}
+
+impl NeuErr {
+	/// Capture the fields of the current `tracing` spans as [`SpanFields`] attachment, if there
+	/// are any. This already happens automatically at error creation, but can be repeated when
+	/// the error crosses into other spans.
+	#[must_use]
+	#[inline]
+	pub fn capture_span(self) -> Self {
+		match SpanFields::capture() {
+			Some(fields) => self.attach_cloneable(fields),
+			None => self,
+		}
+	}
+
+	/// Get the newest snapshot of `tracing` span fields captured for the error.
+	#[must_use]
+	#[inline]
+	pub fn span_fields(&self) -> Option<&SpanFields> {
+		self.attachment::<SpanFields>()
+	}
+}
+
+/// Add the snapshot of the current span fields to the infos of a newly created error.
+pub(crate) fn with_span_fields(mut infos: Infos) -> Infos {
+	if let Some(fields) = SpanFields::capture() {
+		infos.push(Info::Machine(MachineInfo::with_hooks(
+			fields,
+			AttachmentHooks::cloneable::<SpanFields>(),
+		)));
+	}
+	infos
+}
@@ -0,0 +1,88 @@
+//! Tests verifying the number of allocations of error creation.
+//!
+//! Automatic attachments and backtraces allocate at error creation, so the counts are only
+//! verified without the features adding them.
+#![cfg(not(any(
+	feature = "timestamp",
+	feature = "thread-info",
+	feature = "span-fields",
+	feature = "backtrace"
+)))]
+#![allow(clippy::tests_outside_test_module, reason = "Integration tests")]
+
+use ::core::{
+	alloc::{GlobalAlloc, Layout},
+	cell::Cell,
+	error::Error,
+	fmt::{Display, Formatter, Result as FmtResult},
+	hint::black_box,
+};
+use ::neuer_error::NeuErr;
+use ::std::alloc::System;
+
+/// Allocator counting the number of allocations per thread, so that tests running in parallel do
+/// not influence each other.
+struct CountingAllocator;
+
+thread_local! {
+	/// Number of allocations made by the current thread so far.
+	static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: Forwards to the system allocator.
+unsafe impl GlobalAlloc for CountingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		// Ignore allocations during thread teardown, when the counter is no longer available.
+		_ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+		// SAFETY: Forwarded with the same contract.
+		unsafe { System.alloc(layout) }
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		// SAFETY: Forwarded with the same contract.
+		unsafe { System.dealloc(ptr, layout) }
+	}
+}
+
+/// Global allocator of the tests.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Count the allocations made by the function on the current thread.
+fn allocations<T, F>(f: F) -> usize
+where
+	F: FnOnce() -> T,
+{
+	let before = ALLOCATIONS.with(Cell::get);
+	black_box(f());
+	ALLOCATIONS.with(Cell::get) - before
+}
+
+/// Source error without allocations of its own.
+#[derive(Debug)]
+struct SourceError;
+
+impl Display for SourceError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.write_str("SourceError occurred")
+	}
+}
+
+impl Error for SourceError {}
+
+/// Number of allocations of the context infos: Allocated with the first context, or kept inline
+/// with the `smallvec` feature.
+const INFOS: usize = if cfg!(feature = "smallvec") { 0 } else { 1 };
+
+#[test]
+fn from_source_does_not_allocate_infos() {
+	assert_eq!(allocations(|| NeuErr::from_source(SourceError)), 1);
+	assert_eq!(allocations(|| NeuErr::from(SourceError)), 1);
+	assert_eq!(allocations(|| NeuErr::from_source(SourceError).context("context")), 1 + INFOS);
+}
+
+#[test]
+fn new_allocates_infos_once() {
+	assert_eq!(allocations(|| NeuErr::new("message")), 1 + INFOS);
+	assert_eq!(allocations(|| NeuErr::new("message").context("context")), 1 + 2 * INFOS);
+}